@@ -1,28 +1,252 @@
 use crate::channel::AudioChannel;
+use crate::conversion::{
+    balance_to_panning, decibels_to_amplitude, panning_to_balance, semitones_to_pitch, IntoSeconds,
+};
+#[cfg(feature = "output_device")]
+use crate::device::output_device_names;
+use crate::fade::FadeCurve;
+use crate::filter::FilterMode;
+use crate::instance::InstanceHandle;
+use crate::limiter::LimiterSettings;
+use crate::mixer::AudioMixerTrack;
+use crate::play_settings::PlaySettings;
+use crate::reverb::ReverbSettings;
+use crate::sidechain::SidechainSettings;
+use crate::snapshot::{AudioMixerSnapshot, ChannelMixerSettings};
 use crate::source::AudioSource;
+use crate::state::{AudioState, PlaybackState};
+use crate::voices::VoiceStealing;
 use bevy::prelude::Handle;
-use parking_lot::RwLock;
-use std::collections::VecDeque;
+use fastrand::Rng;
+use kira::parameter::tween::Tween;
+use parking_lot::{Mutex, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub enum AudioCommands {
-    Play(PlayAudioSettings),
+    Play(PlayAudioSettings, PlayInstanceSettings),
+    PlayStreamed(PathBuf, bool),
     SetVolume(f32),
+    SetVolumeTween(f32, Tween),
+    SetMuted(bool),
+    /// Affects all channels, the channel of the command is the soloed one
+    SetSolo,
+    SetInstanceVolume(u64, f32),
+    SetInstancePanning(u64, f32),
+    /// The attenuation and panning of an instance from its [`AudioEmitter`](crate::AudioEmitter)
+    SetInstanceSpatial(u64, f32, f32),
+    StopInstance(u64),
+    PauseInstance(u64),
+    ResumeInstance(u64),
+    AddDucking(AudioChannel, DuckingSettings),
+    RemoveDucking(AudioChannel),
     SetPanning(f32),
+    SetPanningTween(f32, Tween),
     SetPitch(f32),
+    SetPitchTween(f32, Tween),
     Stop,
+    StopWithFade(Tween),
+    PlayQueue(Vec<Handle<AudioSource>>, bool),
+    QueueNext(Handle<AudioSource>),
+    ClearQueue,
     Pause,
+    PauseWithFade(Tween),
     Resume,
+    ResumeWithFade(Tween),
+    TogglePause,
+    SeekTo(f64),
+    SeekBy(f64),
+    Restart,
+    SetTrack(AudioMixerTrack),
+    SetMaxVoices(Option<(usize, VoiceStealing)>),
+    StartMetronome(f64, Vec<f64>),
+    StopMetronome,
+    SetMetronomeTempo(f64),
+    /// Play commands whose instances start on the same audio frame
+    PlaySynced(Vec<AudioCommands>),
 }
 
+/// Commands that do not belong to a single channel
+///
+/// They affect all channels, the mixer tracks or the whole output.
+pub enum GlobalAudioCommands {
+    ClearSolo,
+    StopAll,
+    StopAllWithFade(Tween),
+    #[cfg(feature = "output_device")]
+    SetOutputDevice(Option<String>),
+    PauseAll,
+    ResumeAll,
+    Unload(Handle<AudioSource>),
+    Preload(Vec<Handle<AudioSource>>),
+    SetTrackVolume(AudioMixerTrack, f32),
+    SetMasterVolume(f32, Option<Tween>),
+    SetReverb(AudioMixerTrack, ReverbSettings),
+    RemoveReverb(AudioMixerTrack),
+    /// Compresses the first track by the level of the second one
+    AddSidechain(AudioMixerTrack, AudioMixerTrack, SidechainSettings),
+    RemoveSidechain(AudioMixerTrack),
+    SetMasterLimiter(LimiterSettings),
+    RemoveMasterLimiter,
+    SetFilter(AudioMixerTrack, FilterSettings, Option<Tween>),
+    RemoveFilter(AudioMixerTrack),
+    SetPitchShift(AudioMixerTrack, f64, Option<Tween>),
+    RemovePitchShift(AudioMixerTrack),
+    /// Commands that are applied together
+    Batch(Vec<QueuedAudioCommand>),
+}
+
+/// A command in the queue of [`Audio`]
+///
+/// Most queued commands are channel commands, so boxing them would only add allocations.
+#[allow(clippy::large_enum_variant)]
+pub enum QueuedAudioCommand {
+    Channel(AudioCommands, AudioChannel),
+    Global(GlobalAudioCommands),
+}
+
+/// Settings that shape what a play command plays
+///
+/// Together with the channel, these are the key of the arrangement cache, so every field
+/// has to derive `Hash` and `Eq`. Settings of a single instance, like its start position,
+/// belong in [`PlayInstanceSettings`] instead, so plays that only differ in them share
+/// one arrangement.
 #[derive(PartialEq, Eq, Hash, Clone)]
 pub struct PlayAudioSettings {
     pub source: Handle<AudioSource>,
     pub looped: bool,
+    pub loop_region: Option<LoopRegion>,
+    /// Played once before looping the source
+    pub intro: Option<Handle<AudioSource>>,
+    /// Not part of the arrangement, see [`PlayAudioSettings::arrangement_key`]
+    pub cooldown: Cooldown,
+    /// Played backwards, starting from the end
+    pub reversed: bool,
+    /// How often the source plays in a row, if it is not looped
+    pub repeats: u32,
+}
+
+impl PlayAudioSettings {
+    /// The settings that the arrangement is cached for
+    ///
+    /// The cooldown is applied before playing, so plays of the same audio with and
+    /// without a cooldown share their arrangement. Cooldowns are still tracked with the
+    /// full settings as key.
+    pub fn arrangement_key(&self) -> PlayAudioSettings {
+        PlayAudioSettings {
+            cooldown: Cooldown::default(),
+            ..self.clone()
+        }
+    }
+}
+
+/// The part of a sound that is repeated when looping
+///
+/// The positions are stored as bits to be usable as part of the arrangement cache key.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct LoopRegion {
+    start: u64,
+    end: u64,
+}
+
+impl LoopRegion {
+    pub fn new(start: f64, end: f64) -> Self {
+        LoopRegion {
+            start: start.to_bits(),
+            end: end.to_bits(),
+        }
+    }
+
+    pub fn start(&self) -> f64 {
+        f64::from_bits(self.start)
+    }
+
+    pub fn end(&self) -> f64 {
+        f64::from_bits(self.end)
+    }
+}
+
+/// How long an arrangement can not be played again after it started playing
+///
+/// The duration is stored as bits to be usable as part of the cooldown key.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Cooldown(u64);
+
+impl Cooldown {
+    pub fn new(seconds: f64) -> Self {
+        Cooldown(seconds.max(0.0).to_bits())
+    }
+
+    pub fn seconds(&self) -> f64 {
+        f64::from_bits(self.0)
+    }
+}
+
+impl Default for Cooldown {
+    fn default() -> Self {
+        Cooldown::new(0.0)
+    }
+}
+
+pub struct PlayInstanceSettings {
+    pub id: u64,
+    pub pitch_factor: f64,
+    pub start_position: f64,
+    /// Volume of the instance, before the volume of its channel
+    pub volume: f64,
+    /// Panning of the instance instead of the panning of its channel
+    pub panning: Option<f64>,
+    pub fade_in: Option<Tween>,
+    pub crossfade: Option<(Duration, FadeCurve)>,
+    /// Skip playing if the source is already playing in the channel
+    pub skip_if_playing: bool,
+    /// Wait for the next time this interval of the channel's metronome passes
+    pub quantize: Option<f64>,
+    /// Pause the instance before it plays until it is started together with others
+    pub held: bool,
+    /// The id to send with the [`AudioFinished`](crate::AudioFinished) event of the instance
+    pub on_finish: Option<u64>,
+}
+
+/// Options of a single play call that are resolved before queueing it
+#[derive(Default)]
+struct PlayOptions {
+    pitch_variation: Option<f32>,
+    start_position: f64,
+    /// The settings of [`PlaySettings`] that are not part of the played arrangement
+    play_settings: Option<PlaySettings>,
+    crossfade: Option<(Duration, FadeCurve)>,
+    skip_if_playing: bool,
+    name: Option<String>,
+    quantize: Option<f64>,
+    held: bool,
+    on_finish: Option<u64>,
+}
+
+#[derive(Clone, Copy)]
+pub struct DuckingSettings {
+    pub amount: f64,
+    pub attack: Tween,
+    pub release: Tween,
+}
+
+#[derive(Clone, Copy)]
+pub struct FilterSettings {
+    pub mode: FilterMode,
+    pub cutoff: f64,
+    pub resonance: f64,
 }
 
 /// Bevy Audio Resource
 ///
-/// Use this resource to play and control your audio
+/// Use this resource to play and control your audio. Methods without a channel
+/// parameter use the default channel, see [`Audio::set_default_channel`].
+///
+/// The commands are only run by the audio system, so whether a channel is playing is
+/// answered by [`AudioState::is_playing`](crate::AudioState::is_playing) instead.
 /// ```edition2018
 /// # use bevy::prelude::*;
 /// # use bevy_kira_audio::Audio;
@@ -33,299 +257,4692 @@ pub struct PlayAudioSettings {
 /// ```
 #[derive(Default)]
 pub struct Audio {
-    pub(crate) commands: RwLock<VecDeque<(AudioCommands, AudioChannel)>>,
+    pub(crate) commands: RwLock<VecDeque<QueuedAudioCommand>>,
+    pub(crate) initialization: Arc<AudioInitialization>,
+    /// The channel that methods without a channel parameter use
+    pub(crate) default_channel: Arc<RwLock<AudioChannel>>,
+    pub(crate) next_instance_id: Arc<AtomicU64>,
+    pub(crate) rng: Arc<Mutex<Rng>>,
+    /// The latest settings requested for each channel
+    pub(crate) channel_settings: Arc<RwLock<HashMap<AudioChannel, ChannelMixerSettings>>>,
+    /// The number of active instances per channel, as of the latest audio system run
+    pub(crate) instance_counts: Arc<RwLock<HashMap<AudioChannel, usize>>>,
+    /// The latest instance played with each name, per channel
+    pub(crate) named_instances: Arc<RwLock<HashMap<(AudioChannel, String), InstanceHandle>>>,
+    /// The playback state of each instance and channel, as of the latest audio system run
+    pub(crate) playback_states: Arc<RwLock<PlaybackStates>>,
+}
+
+/// Whether the audio manager was created, shared with the audio system
+#[derive(Default)]
+pub(crate) struct AudioInitialization {
+    requested: AtomicBool,
+    done: AtomicBool,
+    available: AtomicBool,
+}
+
+impl AudioInitialization {
+    pub(crate) fn new(done: bool, available: bool) -> Self {
+        AudioInitialization {
+            requested: AtomicBool::new(done),
+            done: AtomicBool::new(done),
+            available: AtomicBool::new(available),
+        }
+    }
+
+    pub(crate) fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn finish(&self, available: bool) {
+        self.available.store(available, Ordering::Relaxed);
+        self.done.store(true, Ordering::Relaxed);
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct PlaybackStates {
+    instances: HashMap<u64, PlaybackState>,
+    channels: HashMap<AudioChannel, PlaybackState>,
 }
 
 impl Audio {
-    /// Play audio in the default channel
+    fn pick_random(&self, audio_sources: &[Handle<AudioSource>]) -> Option<Handle<AudioSource>> {
+        if audio_sources.is_empty() {
+            return None;
+        }
+        let index = self.rng.lock().usize(..audio_sources.len());
+        Some(audio_sources[index].clone())
+    }
+
+    /// Pick an audio source with a probability proportional to its weight
+    ///
+    /// Negative and non-finite weights count as zero. If all weights are zero,
+    /// every audio source is equally likely.
+    fn pick_random_weighted(
+        &self,
+        audio_sources: &[(Handle<AudioSource>, f32)],
+    ) -> Option<Handle<AudioSource>> {
+        let weight = |weight: f32| {
+            if weight.is_finite() && weight > 0.0 {
+                weight
+            } else {
+                0.0
+            }
+        };
+        let total: f32 = audio_sources.iter().map(|(_, w)| weight(*w)).sum();
+        if total <= 0.0 {
+            let handles: Vec<Handle<AudioSource>> = audio_sources
+                .iter()
+                .map(|(handle, _)| handle.clone())
+                .collect();
+            return self.pick_random(&handles);
+        }
+        let mut remaining = self.rng.lock().f32() * total;
+        for (handle, w) in audio_sources.iter() {
+            let w = weight(*w);
+            if remaining < w {
+                return Some(handle.clone());
+            }
+            remaining -= w;
+        }
+        // Rounding can leave a small remainder, which belongs to the last weighted source
+        audio_sources
+            .iter()
+            .rev()
+            .find(|(_, w)| weight(*w) > 0.0)
+            .map(|(handle, _)| handle.clone())
+    }
+
+    fn update_channel_settings(
+        &self,
+        channel_id: &AudioChannel,
+        update: impl FnOnce(&mut ChannelMixerSettings),
+    ) {
+        update(
+            self.channel_settings
+                .write()
+                .entry(channel_id.clone())
+                .or_default(),
+        );
+    }
+
+    fn channel_settings(&self, channel_id: &AudioChannel) -> ChannelMixerSettings {
+        self.channel_settings
+            .read()
+            .get(channel_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn queue_play(
+        &self,
+        settings: PlayAudioSettings,
+        options: PlayOptions,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        let (command, instance) = self.create_play_command(settings, options, channel_id);
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Channel(command, channel_id.clone()));
+        instance
+    }
+
+    fn queue_play_synced(
+        &self,
+        audio_sources: &[Handle<AudioSource>],
+        looped: bool,
+        channel_id: &AudioChannel,
+    ) -> Vec<InstanceHandle> {
+        let mut plays = vec![];
+        let mut instances = vec![];
+        for audio_source in audio_sources {
+            let (command, instance) = self.create_play_command(
+                PlayAudioSettings {
+                    source: audio_source.clone(),
+                    looped,
+                    loop_region: None,
+                    intro: None,
+                    cooldown: Cooldown::default(),
+                    reversed: false,
+                    repeats: 1,
+                },
+                PlayOptions {
+                    held: true,
+                    ..Default::default()
+                },
+                channel_id,
+            );
+            plays.push(command);
+            instances.push(instance);
+        }
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PlaySynced(plays),
+            channel_id.clone(),
+        ));
+        instances
+    }
+
+    fn create_play_command(
+        &self,
+        settings: PlayAudioSettings,
+        options: PlayOptions,
+        channel_id: &AudioChannel,
+    ) -> (AudioCommands, InstanceHandle) {
+        let mut instance = InstanceHandle::new(
+            self.next_instance_id.fetch_add(1, Ordering::Relaxed),
+            channel_id,
+        );
+        if let Some(name) = options.name {
+            instance.name = Some(name.clone());
+            self.named_instances
+                .write()
+                .insert((channel_id.clone(), name), instance.clone());
+        }
+        let mut pitch_factor = options.pitch_variation.map_or(1.0, |semitones| {
+            let offset = (self.rng.lock().f32() * 2.0 - 1.0) * semitones;
+            semitones_to_pitch(offset) as f64
+        });
+        let play_settings = options.play_settings.unwrap_or_default();
+        pitch_factor *= play_settings.playback_rate as f64;
+        let command = AudioCommands::Play(
+            settings,
+            PlayInstanceSettings {
+                id: instance.id,
+                pitch_factor,
+                start_position: options.start_position,
+                volume: play_settings.volume as f64,
+                panning: play_settings.panning.map(|panning| panning as f64),
+                fade_in: play_settings
+                    .fade_in
+                    .map(|(duration, curve)| curve.tween(duration, true)),
+                crossfade: options.crossfade,
+                skip_if_playing: options.skip_if_playing,
+                quantize: options.quantize,
+                held: options.held,
+                on_finish: options.on_finish,
+            },
+        );
+        (command, instance)
+    }
+
+    /// Create the command to play a track of a queue
+    ///
+    /// The command is not queued, since the audio output already holds the command queue.
+    pub(crate) fn create_queue_track_command(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> (AudioCommands, u64) {
+        let (command, instance) = self.create_play_command(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        );
+        (command, instance.id)
+    }
+
+    /// Create the command to play the latest audio of a channel again
+    pub(crate) fn create_restart_command(
+        &self,
+        settings: PlayAudioSettings,
+        channel_id: &AudioChannel,
+    ) -> AudioCommands {
+        self.create_play_command(settings, PlayOptions::default(), channel_id)
+            .0
+    }
+
+    /// Group commands, so that they are applied together in the same frame
+    ///
+    /// All calls on the `Audio` passed to the closure are collected and applied in order
+    /// once all audio sources played in the batch are loaded. No other command
+    /// is applied in between, so for example a volume set in the batch is already
+    /// applied when the audio of the batch starts playing.
     ///
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::Audio;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
     /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
-    ///     audio.play(asset_server.load("audio.mp3"));
+    ///     let channel = AudioChannel::new("sfx".to_owned());
+    ///     audio.batch(|batch| {
+    ///         batch.set_volume_in_channel(0.5, &channel);
+    ///         batch.set_panning_in_channel(0.2, &channel);
+    ///         batch.play_in_channel(asset_server.load("door.mp3"), &channel);
+    ///     });
     /// }
     /// ```
-    pub fn play(&self, audio_source: Handle<AudioSource>) {
-        self.commands.write().push_front((
-            AudioCommands::Play(PlayAudioSettings {
-                source: audio_source,
-                looped: false,
-            }),
-            AudioChannel::default(),
-        ));
+    pub fn batch(&self, build: impl FnOnce(&Audio)) {
+        let batch = Audio {
+            commands: RwLock::new(VecDeque::new()),
+            initialization: self.initialization.clone(),
+            default_channel: self.default_channel.clone(),
+            next_instance_id: self.next_instance_id.clone(),
+            rng: self.rng.clone(),
+            channel_settings: self.channel_settings.clone(),
+            instance_counts: self.instance_counts.clone(),
+            named_instances: self.named_instances.clone(),
+            playback_states: self.playback_states.clone(),
+        };
+        build(&batch);
+        let commands = batch.commands.into_inner();
+        if commands.is_empty() {
+            return;
+        }
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::Batch(
+                commands.into(),
+            )));
     }
 
-    /// Play looped audio in the default channel
+    /// The channel that all methods without a channel parameter use
+    ///
+    /// This is [`AudioChannel::default`] unless changed with [`Audio::set_default_channel`].
+    pub fn default_channel(&self) -> AudioChannel {
+        self.default_channel.read().clone()
+    }
+
+    /// Change the channel that all methods without a channel parameter use
     ///
+    /// Commands that were issued before keep their channel.
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::Audio;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
     /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
-    ///     audio.play_looped(asset_server.load("audio.mp3"));
+    ///     audio.set_default_channel(&AudioChannel::new("sfx".to_owned()));
+    ///     // Plays in the "sfx" channel
+    ///     audio.play(asset_server.load("audio.mp3"));
     /// }
     /// ```
-    pub fn play_looped(&self, audio_source: Handle<AudioSource>) {
-        self.commands.write().push_front((
-            AudioCommands::Play(PlayAudioSettings {
-                source: audio_source,
-                looped: true,
-            }),
-            AudioChannel::default(),
-        ));
+    pub fn set_default_channel(&self, channel_id: &AudioChannel) {
+        *self.default_channel.write() = channel_id.clone();
     }
 
-    /// Stop all audio in the default channel
+    /// Check if audio can be played
+    ///
+    /// This is `false` if no audio device could be initialized, or if the initialization
+    /// is [deferred](crate::AudioSettings::defer_initialization) and did not happen yet.
+    /// Commands issued before a deferred initialization are kept and run in order once
+    /// it happened. Without an audio device, all commands are still accepted, but they
+    /// have no effect.
     ///
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
     /// fn my_system(audio: Res<Audio>) {
-    ///     audio.stop();
+    ///     if !audio.is_available() {
+    ///         warn!("No audio device found; the game will be silent");
+    ///     }
     /// }
     /// ```
-    pub fn stop(&self) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Stop, AudioChannel::default()));
+    pub fn is_available(&self) -> bool {
+        self.initialization.available.load(Ordering::Relaxed)
     }
 
-    /// Pause all audio in the default channel
+    /// The names of all available audio output devices
     ///
+    /// Pass one of them to [`Audio::set_output_device`] to play the audio on that device.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.pause();
+    /// fn settings_menu(audio: Res<Audio>) {
+    ///     for device in audio.output_devices() {
+    ///         info!("Output device: {}", device);
+    ///     }
     /// }
     /// ```
-    pub fn pause(&self) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Pause, AudioChannel::default()));
+    #[cfg(feature = "output_device")]
+    pub fn output_devices(&self) -> Vec<String> {
+        output_device_names()
     }
 
-    /// Resume all audio in the default channel
+    /// Play all audio on the output device with the given name
+    ///
+    /// This creates a new audio backend on the device. Audio that is playing at that time
+    /// stops; audio played afterwards uses the new device. The settings of the channels,
+    /// the effects on mixer tracks and the master limiter are kept. Metronomes keep their
+    /// tempo and start again from the first beat.
     ///
+    /// If the device does not exist or stops working later, for example because it was
+    /// unplugged, an [`AudioError::OutputDevice`](crate::AudioError::OutputDevice) is sent
+    /// and the default output device is used instead.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.resume();
+    /// fn use_headset(audio: Res<Audio>) {
+    ///     if let Some(headset) = audio
+    ///         .output_devices()
+    ///         .into_iter()
+    ///         .find(|device| device.contains("Headset"))
+    ///     {
+    ///         audio.set_output_device(&headset);
+    ///     }
     /// }
     /// ```
-    pub fn resume(&self) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Resume, AudioChannel::default()));
+    #[cfg(feature = "output_device")]
+    pub fn set_output_device(&self, device: &str) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetOutputDevice(Some(device.to_owned())),
+        ));
     }
 
-    /// Set the volume for the default channel
-    ///
-    /// The default value is 1
+    /// Play all audio on the default output device again
     ///
+    /// See [`Audio::set_output_device`] for what happens to playing audio.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_volume(0.5);
+    /// fn reset_output(audio: Res<Audio>) {
+    ///     audio.use_default_output_device();
     /// }
     /// ```
-    pub fn set_volume(&self, volume: f32) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::SetVolume(volume), AudioChannel::default()));
+    #[cfg(feature = "output_device")]
+    pub fn use_default_output_device(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetOutputDevice(None),
+        ));
     }
 
-    /// Set panning for the default channel
-    ///
-    /// The default value is 0.5
-    /// Values up to 1 pan to the right
-    /// Values down to 0 pan to the left
+    /// Create the audio device, if its initialization is deferred
     ///
+    /// See [`AudioSettings::defer_initialization`](crate::AudioSettings::defer_initialization).
+    /// The audio device is created the next time the audio system runs. All commands that
+    /// were issued before, like playing audio or setting volumes, are applied right after.
+    /// Calling this again, or without deferred initialization, does nothing.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_panning(0.9);
+    /// fn enable_sound_on_click(mouse: Res<Input<MouseButton>>, audio: Res<Audio>) {
+    ///     if mouse.just_pressed(MouseButton::Left) {
+    ///         audio.initialize();
+    ///     }
     /// }
     /// ```
-    pub fn set_panning(&self, panning: f32) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::SetPanning(panning), AudioChannel::default()));
+    pub fn initialize(&self) {
+        self.initialization.requested.store(true, Ordering::Relaxed);
     }
 
-    /// Set pitch for the default channel
-    ///
-    /// The default value is 1
+    /// Check if creating the audio device was attempted
     ///
+    /// This is `true` from the start, unless the initialization is
+    /// [deferred](crate::AudioSettings::defer_initialization). Whether the audio device
+    /// could be created is reported by [`Audio::is_available`].
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_pitch(2.0);
+    /// fn sound_prompt(audio: Res<Audio>, mut text: Query<&mut Text>) {
+    ///     for mut text in text.iter_mut() {
+    ///         text.value = if audio.is_initialized() {
+    ///             String::new()
+    ///         } else {
+    ///             "Click to enable sound".to_owned()
+    ///         };
+    ///     }
     /// }
     /// ```
-    pub fn set_pitch(&self, pitch: f32) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::SetPitch(pitch), AudioChannel::default()));
+    pub fn is_initialized(&self) -> bool {
+        self.initialization.done.load(Ordering::Relaxed)
     }
 
-    /// Play audio in the given channel
+    /// The volume of the given channel
     ///
+    /// This is the volume of the latest call to one of the volume setters,
+    /// even if the audio system did not apply it yet.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
-    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
-    ///     audio.play_in_channel(asset_server.load("audio.mp3"), &AudioChannel::new("my-channel".to_owned()));
+    /// fn my_system(audio: Res<Audio>) {
+    ///     let channel = AudioChannel::new("my-channel".to_owned());
+    ///     audio.set_volume_in_channel(0.5, &channel);
+    ///     assert_eq!(audio.channel_volume(&channel), 0.5);
     /// }
     /// ```
-    pub fn play_in_channel(&self, audio_source: Handle<AudioSource>, channel_id: &AudioChannel) {
-        self.commands.write().push_front((
-            AudioCommands::Play(PlayAudioSettings {
-                source: audio_source,
-                looped: false,
-            }),
-            channel_id.clone(),
-        ));
+    pub fn channel_volume(&self, channel_id: &AudioChannel) -> f32 {
+        self.channel_settings(channel_id).volume
     }
 
-    /// Play looped audio in the given channel
+    /// The panning of the given channel
     ///
+    /// This is the panning of the latest call to one of the panning setters,
+    /// even if the audio system did not apply it yet.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
-    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
-    ///     audio.play_looped_in_channel(asset_server.load("audio.mp3"), &AudioChannel::new("my-channel".to_owned()));
+    /// fn my_system(audio: Res<Audio>) {
+    ///     let panning = audio.channel_panning(&AudioChannel::default());
     /// }
     /// ```
-    pub fn play_looped_in_channel(
-        &self,
-        audio_source: Handle<AudioSource>,
-        channel_id: &AudioChannel,
-    ) {
-        self.commands.write().push_front((
-            AudioCommands::Play(PlayAudioSettings {
-                source: audio_source,
-                looped: true,
-            }),
-            channel_id.clone(),
-        ));
+    pub fn channel_panning(&self, channel_id: &AudioChannel) -> f32 {
+        self.channel_settings(channel_id).panning
     }
 
-    /// Stop audio in the given channel
+    /// The pitch of the given channel
     ///
+    /// This is the pitch of the latest call to one of the pitch setters,
+    /// even if the audio system did not apply it yet. Use
+    /// [`pitch_to_semitones`](crate::pitch_to_semitones) to display it in semitones.
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use bevy_kira_audio::{pitch_to_semitones, Audio, AudioChannel};
     ///
     /// fn my_system(audio: Res<Audio>) {
-    ///     audio.stop_channel(&AudioChannel::new("my-channel".to_owned()));
+    ///     let semitones = pitch_to_semitones(audio.channel_pitch(&AudioChannel::default()));
     /// }
     /// ```
-    pub fn stop_channel(&self, channel_id: &AudioChannel) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Stop, channel_id.clone()));
+    pub fn channel_pitch(&self, channel_id: &AudioChannel) -> f32 {
+        self.channel_settings(channel_id).pitch
     }
 
-    /// Pause audio in the given channel
+    /// The stereo balance of the given channel
     ///
+    /// This is the panning of [`Audio::channel_panning`] as a balance from -1 (left)
+    /// to 1 (right). The default panning of 0.5 is a centered balance of 0.
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
     /// fn my_system(audio: Res<Audio>) {
-    ///     audio.pause_channel(&AudioChannel::new("my-channel".to_owned()));
+    ///     let balance = audio.channel_balance(&AudioChannel::default());
     /// }
     /// ```
-    pub fn pause_channel(&self, channel_id: &AudioChannel) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Pause, channel_id.clone()));
+    pub fn channel_balance(&self, channel_id: &AudioChannel) -> f32 {
+        panning_to_balance(self.channel_panning(channel_id))
     }
 
-    /// Resume audio in the given channel
+    /// The number of instances in the given channel that are playing or paused
     ///
+    /// Stopped instances are not counted, but instances that are fading out are.
+    /// The count is updated once per frame by the audio system, so instances
+    /// of play calls in the current frame are not included yet.
+    /// This is the same count as [`AudioState::instance_count`](crate::AudioState::instance_count).
     /// ```edition2018
     /// # use bevy::prelude::*;
     /// # use bevy_kira_audio::{Audio, AudioChannel};
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.resume_channel(&AudioChannel::new("my-channel".to_owned()));
+    /// fn debug_overlay(audio: Res<Audio>) {
+    ///     let voices = audio.instance_count(&AudioChannel::new("sfx".to_owned()));
     /// }
     /// ```
-    pub fn resume_channel(&self, channel_id: &AudioChannel) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::Resume, channel_id.clone()));
+    pub fn instance_count(&self, channel_id: &AudioChannel) -> usize {
+        self.instance_counts
+            .read()
+            .get(channel_id)
+            .copied()
+            .unwrap_or(0)
     }
 
-    /// Set the volume for the given channel
-    ///
-    /// The default value is 1
+    /// All channels that have instances or changed settings, like their volume
     ///
+    /// The channels are in no particular order. Like [`Audio::instance_count`], this is
+    /// updated once per frame. It lists the same channels as
+    /// [`AudioState::active_channels`](crate::AudioState::active_channels).
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_volume_in_channel(0.5, &AudioChannel::new("my-channel".to_owned()));
+    /// fn mute_everything(audio: Res<Audio>) {
+    ///     for channel in audio.active_channels() {
+    ///         audio.set_volume_in_channel(0.0, &channel);
+    ///     }
     /// }
     /// ```
-    pub fn set_volume_in_channel(&self, volume: f32, channel_id: &AudioChannel) {
+    pub fn active_channels(&self) -> Vec<AudioChannel> {
+        self.instance_counts.read().keys().cloned().collect()
+    }
+
+    pub(crate) fn update_instance_counts(&self, audio_state: &AudioState) {
+        *self.instance_counts.write() = audio_state
+            .channels
+            .iter()
+            .map(|(channel, playback_state)| (channel.clone(), playback_state.active_instances))
+            .collect();
+        *self.playback_states.write() = PlaybackStates {
+            instances: audio_state.instances.clone(),
+            channels: audio_state
+                .channels
+                .iter()
+                .map(|(channel, playback_state)| (channel.clone(), playback_state.state))
+                .collect(),
+        };
+    }
+
+    /// Check if a queued command plays an instance that matches the given condition
+    fn is_play_queued(&self, matches: impl Fn(u64, &AudioChannel) -> bool) -> bool {
+        fn plays(
+            command: &AudioCommands,
+            channel_id: &AudioChannel,
+            matches: &impl Fn(u64, &AudioChannel) -> bool,
+        ) -> bool {
+            match command {
+                AudioCommands::Play(_, instance_settings) => {
+                    matches(instance_settings.id, channel_id)
+                }
+                AudioCommands::PlaySynced(commands) => commands
+                    .iter()
+                    .any(|command| plays(command, channel_id, matches)),
+                _ => false,
+            }
+        }
+        fn queued_plays(
+            command: &QueuedAudioCommand,
+            matches: &impl Fn(u64, &AudioChannel) -> bool,
+        ) -> bool {
+            match command {
+                QueuedAudioCommand::Channel(command, channel_id) => {
+                    plays(command, channel_id, matches)
+                }
+                QueuedAudioCommand::Global(GlobalAudioCommands::Batch(commands)) => commands
+                    .iter()
+                    .any(|command| queued_plays(command, matches)),
+                QueuedAudioCommand::Global(_) => false,
+            }
+        }
         self.commands
-            .write()
-            .push_front((AudioCommands::SetVolume(volume), channel_id.clone()));
+            .read()
+            .iter()
+            .any(|command| queued_plays(command, &matches))
     }
 
-    /// Set panning for the given channel
+    /// The playback state of the given instance
     ///
-    /// The default value is 0.5
-    /// Values up to 1 pan to the right
-    /// Values down to 0 pan to the left
+    /// Instances of play calls that did not run yet, for example because their audio
+    /// source is still loading, are [`PlaybackState::Queued`]. Otherwise, the state is
+    /// updated once per frame by the audio system. Instances that finished or were
+    /// stopped are [`PlaybackState::Stopped`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, InstanceHandle, PlaybackState};
+    ///
+    /// fn cutscene(audio: Res<Audio>, voice_line: Res<InstanceHandle>) {
+    ///     match audio.instance_state(&voice_line) {
+    ///         PlaybackState::Stopped => info!("Start the next scene"),
+    ///         PlaybackState::Paused => info!("Show the pause menu"),
+    ///         _ => (),
+    ///     }
+    /// }
+    /// ```
+    pub fn instance_state(&self, instance: &InstanceHandle) -> PlaybackState {
+        if self.is_play_queued(|id, _| id == instance.id) {
+            return PlaybackState::Queued;
+        }
+        self.playback_states
+            .read()
+            .instances
+            .get(&instance.id)
+            .copied()
+            .unwrap_or(PlaybackState::Stopped)
+    }
+
+    /// The playback state of the given channel
     ///
+    /// See [`AudioState::channel_state`](crate::AudioState::channel_state) for how the
+    /// states of the instances in the channel are combined. A channel without playing
+    /// or fading instances is [`PlaybackState::Queued`] while a play call for it did
+    /// not run yet.
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use bevy_kira_audio::{Audio, AudioChannel, PlaybackState};
     ///
     /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_panning_in_channel(0.9, &AudioChannel::new("my-channel".to_owned()));
+    ///     let music = AudioChannel::new("music".to_owned());
+    ///     if audio.channel_state(&music) == PlaybackState::Stopped {
+    ///         info!("The music is over");
+    ///     }
     /// }
     /// ```
-    pub fn set_panning_in_channel(&self, panning: f32, channel_id: &AudioChannel) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::SetPanning(panning), channel_id.clone()));
+    pub fn channel_state(&self, channel_id: &AudioChannel) -> PlaybackState {
+        let state = self
+            .playback_states
+            .read()
+            .channels
+            .get(channel_id)
+            .copied()
+            .unwrap_or(PlaybackState::Stopped);
+        if state != PlaybackState::Playing
+            && self.is_play_queued(|_, channel| channel == channel_id)
+        {
+            return PlaybackState::Queued;
+        }
+        state
     }
 
-    /// Set pitch for the given channel
+    /// Play audio in the default channel
     ///
-    /// The default value is 1
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play(asset_server.load("audio.mp3"));
+    /// }
+    /// ```
+    pub fn play(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            &self.default_channel(),
+        )
+    }
+
+    /// Play a short sound without caring about channels
+    ///
+    /// The sound plays in an internal channel that is not affected by the settings of
+    /// the default channel. Nothing is kept once it finished, which makes this a good
+    /// fit for UI clicks and other transient sound effects. Commands for all channels,
+    /// like [`Audio::pause_all`], still apply to it.
     ///
     /// ```edition2018
     /// # use bevy::prelude::*;
-    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use bevy_kira_audio::Audio;
     ///
-    /// fn my_system(audio: Res<Audio>) {
-    ///     audio.set_pitch_in_channel(2.0, &AudioChannel::new("my-channel".to_owned()));
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_oneshot(asset_server.load("click.ogg"));
     /// }
     /// ```
-    pub fn set_pitch_in_channel(&self, pitch: f32, channel_id: &AudioChannel) {
-        self.commands
-            .write()
-            .push_front((AudioCommands::SetPitch(pitch), channel_id.clone()));
+    pub fn play_oneshot(&self, audio_source: Handle<AudioSource>) {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            &AudioChannel::oneshot(),
+        );
+    }
+
+    /// Play looped audio in the default channel
+    ///
+    /// The audio wraps around without a gap, and the frames around the wrap are interpolated
+    /// across it, so loops that are seamless in the file do not click.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped(asset_server.load("audio.mp3"));
+    /// }
+    /// ```
+    pub fn play_looped(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            &self.default_channel(),
+        )
+    }
+
+    /// Play audio in the default channel under the given name
+    ///
+    /// The instance can then be controlled by its name, for example with [`Audio::stop_named`].
+    /// Names are scoped per channel. Playing another instance with the same name in the
+    /// same channel makes the name refer to the new instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_named(asset_server.load("horn.mp3"), "horn");
+    /// }
+    /// ```
+    pub fn play_named(&self, audio_source: Handle<AudioSource>, name: &str) -> InstanceHandle {
+        self.play_named_in_channel(audio_source, name, &self.default_channel())
+    }
+
+    /// Play looped audio in the default channel under the given name
+    ///
+    /// See [`Audio::play_named`] for how names work.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_named(asset_server.load("engine.mp3"), "engine_loop");
+    /// }
+    /// ```
+    pub fn play_looped_named(
+        &self,
+        audio_source: Handle<AudioSource>,
+        name: &str,
+    ) -> InstanceHandle {
+        self.play_looped_named_in_channel(audio_source, name, &self.default_channel())
+    }
+
+    /// Stream an audio file from disk in the default channel
+    ///
+    /// Instead of decoding the whole file into memory when it is loaded, the file is decoded
+    /// in small pieces on a separate thread while it plays. This is useful for long music
+    /// tracks, but playback starts slightly later than for a loaded [`AudioSource`] and
+    /// streams only follow the volume of their channel. Use loaded audio sources for short
+    /// sound effects.
+    ///
+    /// The path is a file system path, not an asset path. Only ogg files can be streamed.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.play_streamed("assets/soundtrack.ogg");
+    /// }
+    /// ```
+    pub fn play_streamed(&self, path: impl Into<PathBuf>) {
+        self.play_streamed_in_channel(path, &self.default_channel());
+    }
+
+    /// Stream an audio file from disk in a loop in the default channel
+    ///
+    /// See [`Audio::play_streamed`] for the differences to loaded audio sources.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.play_streamed_looped("assets/soundtrack.ogg");
+    /// }
+    /// ```
+    pub fn play_streamed_looped(&self, path: impl Into<PathBuf>) {
+        self.play_streamed_looped_in_channel(path, &self.default_channel());
+    }
+
+    /// Play audio in the default channel, starting at the given position
+    ///
+    /// The position is given in seconds or as a [`Duration`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_from(asset_server.load("audio.mp3"), 3.5);
+    /// }
+    /// ```
+    pub fn play_from(
+        &self,
+        audio_source: Handle<AudioSource>,
+        start_position: impl IntoSeconds,
+    ) -> InstanceHandle {
+        self.play_from_in_channel(audio_source, start_position, &self.default_channel())
+    }
+
+    /// Play looped audio in the default channel, starting at the given position
+    ///
+    /// Only the first playthrough starts at the given position.
+    /// Every following loop starts at the beginning again.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_from(asset_server.load("audio.mp3"), 3.5);
+    /// }
+    /// ```
+    pub fn play_looped_from(
+        &self,
+        audio_source: Handle<AudioSource>,
+        start_position: impl IntoSeconds,
+    ) -> InstanceHandle {
+        self.play_looped_from_in_channel(audio_source, start_position, &self.default_channel())
+    }
+
+    /// Play audio in the default channel and loop a part of it
+    ///
+    /// The audio plays from the beginning until `loop_end` once, then jumps back
+    /// to `loop_start` every time it reaches `loop_end`. This allows music with an
+    /// intro that should only be heard once. A `loop_end` after the end of the audio
+    /// is clamped to its duration. Both are given in seconds or as a [`Duration`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_with_region(asset_server.load("music.ogg"), 8.0, 72.0);
+    /// }
+    /// ```
+    pub fn play_looped_with_region(
+        &self,
+        audio_source: Handle<AudioSource>,
+        loop_start: impl IntoSeconds,
+        loop_end: impl IntoSeconds,
+    ) -> InstanceHandle {
+        self.play_looped_with_region_in_channel(
+            audio_source,
+            loop_start,
+            loop_end,
+            &self.default_channel(),
+        )
+    }
+
+    /// Play an intro once in the default channel, then loop the other audio source
+    ///
+    /// The loop starts exactly at the end of the intro without any gap.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_with_intro(asset_server.load("intro.ogg"), asset_server.load("loop.ogg"));
+    /// }
+    /// ```
+    pub fn play_looped_with_intro(
+        &self,
+        intro: Handle<AudioSource>,
+        loop_body: Handle<AudioSource>,
+    ) -> InstanceHandle {
+        self.play_looped_with_intro_in_channel(intro, loop_body, &self.default_channel())
+    }
+
+    /// Fade from the audio playing in the default channel to the given audio source
+    ///
+    /// All audio in the channel fades out over the given duration while the new
+    /// audio fades in. The faded out audio is stopped afterwards.
+    /// The fades use the [`FadeCurve::EqualPower`] curve.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.crossfade(asset_server.load("next_track.ogg"), Duration::from_secs(3));
+    /// }
+    /// ```
+    pub fn crossfade(
+        &self,
+        audio_source: Handle<AudioSource>,
+        duration: Duration,
+    ) -> InstanceHandle {
+        self.crossfade_in_channel(audio_source, duration, &self.default_channel())
+    }
+
+    /// Fade from the audio playing in the default channel to the given audio source
+    /// using the given fade curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.crossfade_with_curve(
+    ///         asset_server.load("next_track.ogg"),
+    ///         Duration::from_secs(3),
+    ///         FadeCurve::Linear,
+    ///     );
+    /// }
+    /// ```
+    pub fn crossfade_with_curve(
+        &self,
+        audio_source: Handle<AudioSource>,
+        duration: Duration,
+        curve: FadeCurve,
+    ) -> InstanceHandle {
+        self.crossfade_with_curve_in_channel(audio_source, duration, curve, &self.default_channel())
+    }
+
+    /// Play the given audio sources one after another in the default channel
+    ///
+    /// This replaces all audio playing in the channel. If `looped` is true, the
+    /// queue starts from the beginning after the last audio source finished.
+    /// Stopping the channel also clears its queue.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_queue(
+    ///         vec![asset_server.load("track_1.ogg"), asset_server.load("track_2.ogg")],
+    ///         true,
+    ///     );
+    /// }
+    /// ```
+    pub fn play_queue(&self, audio_sources: Vec<Handle<AudioSource>>, looped: bool) {
+        self.play_queue_in_channel(audio_sources, looped, &self.default_channel());
+    }
+
+    /// Add an audio source to the end of the queue of the default channel
+    ///
+    /// If the queue already finished, the audio source starts playing right away.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.queue_next(asset_server.load("track_3.ogg"));
+    /// }
+    /// ```
+    pub fn queue_next(&self, audio_source: Handle<AudioSource>) {
+        self.queue_next_in_channel(audio_source, &self.default_channel());
+    }
+
+    /// Clear the queue of the default channel
+    ///
+    /// The currently playing audio source keeps playing, but no further audio is started.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.clear_queue();
+    /// }
+    /// ```
+    pub fn clear_queue(&self) {
+        self.clear_queue_in_channel(&self.default_channel());
+    }
+
+    /// Play one of the given audio sources, chosen at random, in the default channel
+    ///
+    /// Returns `None` if no audio source was given.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_random(&[
+    ///         asset_server.load("step_1.mp3"),
+    ///         asset_server.load("step_2.mp3"),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn play_random(&self, audio_sources: &[Handle<AudioSource>]) -> Option<InstanceHandle> {
+        self.play_random_in_channel(audio_sources, &self.default_channel())
+    }
+
+    /// Play one of the given audio sources in the default channel, chosen by weight
+    ///
+    /// The chance of each audio source is its weight divided by the sum of all weights.
+    /// Negative weights count as zero. If all weights are zero, every audio source
+    /// is equally likely. Returns `None` if no audio source was given.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_random_weighted(&[
+    ///         (asset_server.load("step_1.mp3"), 10.0),
+    ///         (asset_server.load("step_squeak.mp3"), 1.0),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn play_random_weighted(
+        &self,
+        audio_sources: &[(Handle<AudioSource>, f32)],
+    ) -> Option<InstanceHandle> {
+        self.play_random_weighted_in_channel(audio_sources, &self.default_channel())
+    }
+
+    /// Play audio in the default channel with a random pitch offset
+    ///
+    /// The pitch is shifted by a random amount of up to `semitones` in either direction.
+    /// The variation only applies to this instance and does not change the channel pitch.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_pitch_variation(asset_server.load("step.mp3"), 2.0);
+    /// }
+    /// ```
+    pub fn play_with_pitch_variation(
+        &self,
+        audio_source: Handle<AudioSource>,
+        semitones: f32,
+    ) -> InstanceHandle {
+        self.play_with_pitch_variation_in_channel(audio_source, semitones, &self.default_channel())
+    }
+
+    /// Play audio in the default channel, ignoring further plays of it for the given cooldown
+    ///
+    /// Plays of the audio with the same cooldown that are started within the cooldown
+    /// after this one are skipped. This prevents phasing when the same one-shot is
+    /// triggered several times in the same frame.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_cooldown(asset_server.load("coin.mp3"), Duration::from_millis(50));
+    /// }
+    /// ```
+    pub fn play_with_cooldown(
+        &self,
+        audio_source: Handle<AudioSource>,
+        cooldown: Duration,
+    ) -> InstanceHandle {
+        self.play_with_cooldown_in_channel(audio_source, cooldown, &self.default_channel())
+    }
+
+    /// Play audio backwards in the default channel
+    ///
+    /// The instance starts at the end of the audio and stops when it reaches the start.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_reversed(asset_server.load("rewind.mp3"));
+    /// }
+    /// ```
+    pub fn play_reversed(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.play_reversed_in_channel(audio_source, &self.default_channel())
+    }
+
+    /// Play looped audio backwards in the default channel
+    ///
+    /// Reversed instances repeat the looped part from its end back to its start.
+    /// Audio after the loop end is never heard, so the intro of a source played
+    /// with [`play_looped_with_intro`](Audio::play_looped_with_intro) is not reached.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_reversed(asset_server.load("rewind.mp3"));
+    /// }
+    /// ```
+    pub fn play_looped_reversed(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.play_looped_reversed_in_channel(audio_source, &self.default_channel())
+    }
+
+    /// Play audio the given number of times in a row in the default channel
+    ///
+    /// The repetitions are one instance that finishes after the last of them, without
+    /// gaps in between. Playing audio once is the same as [`Audio::play`]. Playing it
+    /// zero times does nothing, and the returned handle does not refer to any instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_repeated(asset_server.load("stinger.ogg"), 3);
+    /// }
+    /// ```
+    pub fn play_repeated(&self, audio_source: Handle<AudioSource>, times: u32) -> InstanceHandle {
+        self.play_repeated_in_channel(audio_source, times, &self.default_channel())
+    }
+
+    /// Play audio in the default channel on the next beat interval of its metronome
+    ///
+    /// The instance is added right away, but only starts when the given interval of the
+    /// channel's metronome passes next. With the metronome's intervals in beats, `1.0`
+    /// waits for the next beat and `4.0` for the next bar in 4/4 time. The start is timed
+    /// by the audio backend, so sounds started like this play in sync with each other.
+    ///
+    /// The interval has to be one of the intervals the metronome was started with
+    /// (see [`Audio::start_metronome`]). Without a metronome, the audio plays immediately.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn add_drums(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_quantized(asset_server.load("drums.ogg"), 4.0);
+    /// }
+    /// ```
+    pub fn play_quantized(
+        &self,
+        audio_source: Handle<AudioSource>,
+        interval: f64,
+    ) -> InstanceHandle {
+        self.play_quantized_in_channel(audio_source, interval, &self.default_channel())
+    }
+
+    /// Play looped audio in the default channel on the next beat interval of its metronome
+    ///
+    /// This is useful to layer stems of the same track, which have to stay in sync.
+    /// See [`Audio::play_quantized`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn add_bass(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_quantized(asset_server.load("bass.ogg"), 4.0);
+    /// }
+    /// ```
+    pub fn play_looped_quantized(
+        &self,
+        audio_source: Handle<AudioSource>,
+        interval: f64,
+    ) -> InstanceHandle {
+        self.play_looped_quantized_in_channel(audio_source, interval, &self.default_channel())
+    }
+
+    /// Play several audio sources in the default channel, starting on the same audio frame
+    ///
+    /// This is meant for the stems of adaptive music, which have to stay in sync. No stem
+    /// starts before all of them are loaded. The returned handles are in the order of the
+    /// sources; use them with [`Audio::set_instance_volume`] to fade single stems in and out.
+    /// Playback rate changes of the channel apply to all stems alike.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn start_music(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let stems = audio.play_synced(&[
+    ///         asset_server.load("music/drums.ogg"),
+    ///         asset_server.load("music/strings.ogg"),
+    ///     ]);
+    ///     audio.set_instance_volume(0.0, &stems[1]);
+    /// }
+    /// ```
+    pub fn play_synced(&self, audio_sources: &[Handle<AudioSource>]) -> Vec<InstanceHandle> {
+        self.play_synced_in_channel(audio_sources, &self.default_channel())
+    }
+
+    /// Loop several audio sources in the default channel, starting on the same audio frame
+    ///
+    /// The stems only stay in sync over several loops if they have the same duration.
+    /// See [`Audio::play_synced`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn start_music(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_synced(&[
+    ///         asset_server.load("music/drums.ogg"),
+    ///         asset_server.load("music/strings.ogg"),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn play_looped_synced(&self, audio_sources: &[Handle<AudioSource>]) -> Vec<InstanceHandle> {
+        self.play_looped_synced_in_channel(audio_sources, &self.default_channel())
+    }
+
+    /// Play audio in the default channel unless it is already playing there
+    ///
+    /// This prevents stacking instances of the same audio when a system runs repeatedly.
+    /// Paused instances of the audio also count as playing. If the audio is skipped,
+    /// the returned handle does not refer to any instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_if_not_playing(asset_server.load("alarm.mp3"));
+    /// }
+    /// ```
+    pub fn play_if_not_playing(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.play_if_not_playing_in_channel(audio_source, &self.default_channel())
+    }
+
+    /// Play looped audio in the default channel unless it is already playing there
+    ///
+    /// Paused instances of the audio also count as playing. If the audio is skipped,
+    /// the returned handle does not refer to any instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_if_not_playing(asset_server.load("wind.ogg"));
+    /// }
+    /// ```
+    pub fn play_looped_if_not_playing(&self, audio_source: Handle<AudioSource>) -> InstanceHandle {
+        self.play_looped_if_not_playing_in_channel(audio_source, &self.default_channel())
+    }
+
+    /// Play audio in the default channel and tag its [`AudioFinished`](crate::AudioFinished) event
+    ///
+    /// The event carries the given id in `on_finish`. A system can match on it to run
+    /// code once this particular instance finished playing, without keeping its handle.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioFinished};
+    /// const DOOR_CLOSED: u64 = 1;
+    ///
+    /// fn close_door(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_on_finish(asset_server.load("door.ogg"), DOOR_CLOSED);
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct FinishedReader {
+    ///     reader: EventReader<AudioFinished>,
+    /// }
+    ///
+    /// fn door_closed(mut state: Local<FinishedReader>, events: Res<Events<AudioFinished>>) {
+    ///     for event in state.reader.iter(&events) {
+    ///         if event.on_finish == Some(DOOR_CLOSED) {
+    ///             info!("The door is closed");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn play_with_on_finish(
+        &self,
+        audio_source: Handle<AudioSource>,
+        on_finish: u64,
+    ) -> InstanceHandle {
+        self.play_with_on_finish_in_channel(audio_source, on_finish, &self.default_channel())
+    }
+
+    /// Play audio in the default channel with the given settings
+    ///
+    /// Unlike setting the volume or panning of the returned instance afterwards, the
+    /// settings apply from the first frame on.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, PlaySettings};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with(
+    ///         asset_server.load("engine.ogg"),
+    ///         PlaySettings::new()
+    ///             .volume(0.5)
+    ///             .panning(0.2)
+    ///             .playback_rate(1.2)
+    ///             .fade_in(Duration::from_millis(300))
+    ///             .start_from(1.5)
+    ///             .loop_region(1.5, 4.0),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_with(
+        &self,
+        audio_source: Handle<AudioSource>,
+        settings: PlaySettings,
+    ) -> InstanceHandle {
+        self.play_with_in_channel(audio_source, settings, &self.default_channel())
+    }
+
+    /// Seed the random number generator used by `play_random` and pitch variations
+    ///
+    /// The same seed always results in the same sequence of choices.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_random_seed(42);
+    /// }
+    /// ```
+    pub fn set_random_seed(&self, seed: u64) {
+        self.rng.lock().seed(seed);
+    }
+
+    /// Stop all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop();
+    /// }
+    /// ```
+    pub fn stop(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Stop,
+            self.default_channel(),
+        ));
+    }
+
+    /// Fade out and then stop all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_with_fade(Duration::from_secs(2));
+    /// }
+    /// ```
+    pub fn stop_with_fade(&self, fade_duration: Duration) {
+        self.stop_channel_with_fade(fade_duration, &self.default_channel());
+    }
+
+    /// Fade out with the given curve and then stop all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_with_fade_curve(Duration::from_secs(2), FadeCurve::EqualPower);
+    /// }
+    /// ```
+    pub fn stop_with_fade_curve(&self, fade_duration: Duration, curve: FadeCurve) {
+        self.stop_channel_with_fade_curve(fade_duration, curve, &self.default_channel());
+    }
+
+    /// Stop all audio in all channels
+    ///
+    /// Queued audio and streams are stopped as well. To fade out instead, for example
+    /// when returning to the main menu, see [`Audio::fade_out_all`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn enter_main_menu(audio: Res<Audio>) {
+    ///     audio.stop_all();
+    /// }
+    /// ```
+    pub fn stop_all(&self) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::StopAll));
+    }
+
+    /// Fade out and then stop all audio in all channels
+    ///
+    /// Streamed audio does not support fading and stops right away. To stop all audio
+    /// without a fade, see [`Audio::stop_all`]. To fade out when the app exits, see
+    /// [`AudioSettings::fade_out_on_exit`](crate::AudioSettings::fade_out_on_exit).
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.fade_out_all(Duration::from_millis(500));
+    /// }
+    /// ```
+    pub fn fade_out_all(&self, fade_duration: Duration) {
+        self.fade_out_all_with_curve(fade_duration, FadeCurve::Linear);
+    }
+
+    /// Fade out with the given curve and then stop all audio in all channels
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.fade_out_all_with_curve(Duration::from_secs(1), FadeCurve::Logarithmic);
+    /// }
+    /// ```
+    pub fn fade_out_all_with_curve(&self, fade_duration: Duration, curve: FadeCurve) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::StopAllWithFade(curve.tween(fade_duration, false)),
+        ));
+    }
+
+    /// Pause all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause();
+    /// }
+    /// ```
+    pub fn pause(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Pause,
+            self.default_channel(),
+        ));
+    }
+
+    /// Fade out and then pause all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_with_fade(Duration::from_millis(500));
+    /// }
+    /// ```
+    pub fn pause_with_fade(&self, fade_duration: Duration) {
+        self.pause_channel_with_fade(fade_duration, &self.default_channel());
+    }
+
+    /// Fade out with the given curve and then pause all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_with_fade_curve(Duration::from_millis(500), FadeCurve::EqualPower);
+    /// }
+    /// ```
+    pub fn pause_with_fade_curve(&self, fade_duration: Duration, curve: FadeCurve) {
+        self.pause_channel_with_fade_curve(fade_duration, curve, &self.default_channel());
+    }
+
+    /// Pause the default channel if it is not paused, otherwise resume it
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn pause_button(audio: Res<Audio>) {
+    ///     audio.toggle_pause();
+    /// }
+    /// ```
+    pub fn toggle_pause(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::TogglePause,
+            self.default_channel(),
+        ));
+    }
+
+    /// Resume all audio in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume();
+    /// }
+    /// ```
+    pub fn resume(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Resume,
+            self.default_channel(),
+        ));
+    }
+
+    /// Resume all audio in the default channel, fading it in
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_with_fade(Duration::from_millis(500));
+    /// }
+    /// ```
+    pub fn resume_with_fade(&self, fade_duration: Duration) {
+        self.resume_channel_with_fade(fade_duration, &self.default_channel());
+    }
+
+    /// Resume all audio in the default channel, fading it in with the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_with_fade_curve(Duration::from_millis(500), FadeCurve::EqualPower);
+    /// }
+    /// ```
+    pub fn resume_with_fade_curve(&self, fade_duration: Duration, curve: FadeCurve) {
+        self.resume_channel_with_fade_curve(fade_duration, curve, &self.default_channel());
+    }
+
+    /// Play all audio in the default channel from the beginning
+    ///
+    /// If nothing is playing anymore, the audio that was played last in the channel
+    /// is played again with the same looping settings.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.restart();
+    /// }
+    /// ```
+    pub fn restart(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Restart,
+            self.default_channel(),
+        ));
+    }
+
+    /// Jump to a position in all audio in the default channel
+    ///
+    /// The position is given in seconds or as a [`Duration`].
+    /// Positions are clamped to the duration of the audio. For looped audio,
+    /// positions after the end wrap around within the looped part.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.seek_to(12.5);
+    ///     audio.seek_to(Duration::from_millis(12_500));
+    /// }
+    /// ```
+    pub fn seek_to(&self, position: impl IntoSeconds) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SeekTo(position.into_seconds()),
+            self.default_channel(),
+        ));
+    }
+
+    /// Move the position of all audio in the default channel by the given time
+    ///
+    /// The offset is given in seconds or as a [`Duration`]. Negative offsets in seconds
+    /// seek backwards. The resulting positions are limited
+    /// like for [`Audio::seek_to`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.seek_by(-5.0);
+    /// }
+    /// ```
+    pub fn seek_by(&self, offset: impl IntoSeconds) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SeekBy(offset.into_seconds()),
+            self.default_channel(),
+        ));
+    }
+
+    /// Pause all audio in all channels
+    ///
+    /// This is useful when pausing the game.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_all();
+    /// }
+    /// ```
+    pub fn pause_all(&self) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::PauseAll));
+    }
+
+    /// Resume all audio that was paused by [`Audio::pause_all`]
+    ///
+    /// Audio that was already paused before calling `pause_all` stays paused.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_all();
+    /// }
+    /// ```
+    pub fn resume_all(&self) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::ResumeAll));
+    }
+
+    /// Set the volume for the default channel
+    ///
+    /// The same as [`Audio::set_channel_volume`] for the default channel.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume(0.5);
+    /// }
+    /// ```
+    pub fn set_volume(&self, volume: f32) {
+        self.set_channel_volume(volume, &self.default_channel());
+    }
+
+    /// Mute or unmute the default channel
+    ///
+    /// Muting keeps the channel volume, so it is restored when unmuting.
+    /// Audio played in a muted channel starts silent.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_muted(true);
+    /// }
+    /// ```
+    pub fn set_muted(&self, muted: bool) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetMuted(muted),
+            self.default_channel(),
+        ));
+    }
+
+    /// Silence all channels except the given one, until [`Audio::clear_solo`] is called
+    ///
+    /// This is meant for finding out which channel plays a sound while developing. The volumes
+    /// and mute states of the channels are kept and apply again once the solo is cleared.
+    /// Soloing another channel replaces the current solo.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn solo_footsteps(keys: Res<Input<KeyCode>>, audio: Res<Audio>) {
+    ///     if keys.just_pressed(KeyCode::F1) {
+    ///         audio.set_solo(&AudioChannel::new("footsteps".to_owned()));
+    ///     }
+    ///     if keys.just_released(KeyCode::F1) {
+    ///         audio.clear_solo();
+    ///     }
+    /// }
+    /// ```
+    pub fn set_solo(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetSolo,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Make all channels audible again after [`Audio::set_solo`]
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.clear_solo();
+    /// }
+    /// ```
+    pub fn clear_solo(&self) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::ClearSolo));
+    }
+
+    /// Smoothly change the volume for the default channel over the given duration
+    ///
+    /// Audio played during the transition starts at the new volume.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_with_tween(0.2, Duration::from_secs(1));
+    /// }
+    /// ```
+    pub fn set_volume_with_tween(&self, volume: f32, tween_duration: Duration) {
+        self.set_volume_with_tween_in_channel(volume, tween_duration, &self.default_channel());
+    }
+
+    /// Smoothly change the volume for the default channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_with_tween_curve(0.2, Duration::from_secs(4), FadeCurve::Logarithmic);
+    /// }
+    /// ```
+    pub fn set_volume_with_tween_curve(
+        &self,
+        volume: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+    ) {
+        self.set_volume_with_tween_curve_in_channel(
+            volume,
+            tween_duration,
+            curve,
+            &self.default_channel(),
+        );
+    }
+
+    /// Set the volume for the default channel in decibels
+    ///
+    /// The default value is 0 dB. Very low values and negative infinity are silent.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_db(-6.0);
+    /// }
+    /// ```
+    pub fn set_volume_db(&self, decibels: f32) {
+        self.set_volume(decibels_to_amplitude(decibels));
+    }
+
+    /// Set panning for the default channel
+    ///
+    /// The default value is 0.5
+    /// Values up to 1 pan to the right
+    /// Values down to 0 pan to the left
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_panning(0.9);
+    /// }
+    /// ```
+    pub fn set_panning(&self, panning: f32) {
+        self.update_channel_settings(&self.default_channel(), |settings| {
+            settings.panning = panning
+        });
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPanning(panning),
+            self.default_channel(),
+        ));
+    }
+
+    /// Smoothly change the panning for the default channel over the given duration
+    ///
+    /// [`Audio::channel_panning`] returns the target panning right away.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_panning_with_tween(0.1, Duration::from_millis(500));
+    /// }
+    /// ```
+    pub fn set_panning_with_tween(&self, panning: f32, tween_duration: Duration) {
+        self.set_panning_with_tween_in_channel(panning, tween_duration, &self.default_channel());
+    }
+
+    /// Smoothly change the panning for the default channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_panning_with_tween_curve(0.1, Duration::from_millis(500), FadeCurve::EqualPower);
+    /// }
+    /// ```
+    pub fn set_panning_with_tween_curve(
+        &self,
+        panning: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+    ) {
+        self.set_panning_with_tween_curve_in_channel(
+            panning,
+            tween_duration,
+            curve,
+            &self.default_channel(),
+        );
+    }
+
+    /// Set pitch for the default channel
+    ///
+    /// The default value is 1
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_pitch(2.0);
+    /// }
+    /// ```
+    pub fn set_pitch(&self, pitch: f32) {
+        self.update_channel_settings(&self.default_channel(), |settings| settings.pitch = pitch);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPitch(pitch),
+            self.default_channel(),
+        ));
+    }
+
+    /// Smoothly change the pitch for the default channel over the given duration
+    ///
+    /// This bends the pitch and speed of all audio in the channel, for example for
+    /// slow motion effects. [`Audio::channel_pitch`] returns the target pitch right away.
+    /// Combine it with [`Audio::set_pitch_shift`] to change the speed but keep the pitch.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn enter_slow_motion(audio: Res<Audio>) {
+    ///     audio.set_pitch_with_tween(0.5, Duration::from_millis(800));
+    /// }
+    /// ```
+    pub fn set_pitch_with_tween(&self, pitch: f32, tween_duration: Duration) {
+        self.set_pitch_with_tween_in_channel(pitch, tween_duration, &self.default_channel());
+    }
+
+    /// Smoothly change the pitch for the default channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn enter_slow_motion(audio: Res<Audio>) {
+    ///     audio.set_pitch_with_tween_curve(0.5, Duration::from_millis(800), FadeCurve::Logarithmic);
+    /// }
+    /// ```
+    pub fn set_pitch_with_tween_curve(
+        &self,
+        pitch: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+    ) {
+        self.set_pitch_with_tween_curve_in_channel(
+            pitch,
+            tween_duration,
+            curve,
+            &self.default_channel(),
+        );
+    }
+
+    /// Set pitch for the default channel as an offset in semitones
+    ///
+    /// 12 semitones are one octave. Negative and fractional values are supported.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_pitch_semitones(-2.5);
+    /// }
+    /// ```
+    pub fn set_pitch_semitones(&self, semitones: f32) {
+        self.set_pitch(semitones_to_pitch(semitones));
+    }
+
+    /// Set the stereo balance for the default channel
+    ///
+    /// The default value is 0, which is centered.
+    /// Values up to 1 move the audio to the right, values down to -1 to the left.
+    /// This is a more intuitive form of [`Audio::set_panning`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_balance(-0.5);
+    /// }
+    /// ```
+    pub fn set_balance(&self, balance: f32) {
+        self.set_panning(balance_to_panning(balance));
+    }
+
+    /// Play audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_in_channel(asset_server.load("audio.mp3"), &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn play_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play looped audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_in_channel(asset_server.load("audio.mp3"), &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn play_looped_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel under the given name
+    ///
+    /// See [`Audio::play_named`] for how names work.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_named_in_channel(
+    ///         asset_server.load("alarm.mp3"),
+    ///         "alarm",
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_named_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        name: &str,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                name: Some(name.to_owned()),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play looped audio in the given channel under the given name
+    ///
+    /// See [`Audio::play_named`] for how names work.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_named_in_channel(
+    ///         asset_server.load("engine.mp3"),
+    ///         "engine_loop",
+    ///         &AudioChannel::new("vehicles".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_named_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        name: &str,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                name: Some(name.to_owned()),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel, starting at the given position
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_from_in_channel(asset_server.load("audio.mp3"), 3.5, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn play_from_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        start_position: impl IntoSeconds,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                start_position: start_position.into_seconds(),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play looped audio in the given channel, starting at the given position
+    ///
+    /// Only the first playthrough starts at the given position.
+    /// Every following loop starts at the beginning again.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_from_in_channel(asset_server.load("audio.mp3"), 3.5, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn play_looped_from_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        start_position: impl IntoSeconds,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                start_position: start_position.into_seconds(),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel and loop a part of it
+    ///
+    /// See [`Audio::play_looped_with_region`] for details.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_with_region_in_channel(
+    ///         asset_server.load("music.ogg"),
+    ///         8.0,
+    ///         72.0,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_with_region_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        loop_start: impl IntoSeconds,
+        loop_end: impl IntoSeconds,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: Some(LoopRegion::new(
+                    loop_start.into_seconds(),
+                    loop_end.into_seconds(),
+                )),
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play an intro once in the given channel, then loop the other audio source
+    ///
+    /// The loop starts exactly at the end of the intro without any gap.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_with_intro_in_channel(
+    ///         asset_server.load("intro.ogg"),
+    ///         asset_server.load("loop.ogg"),
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_with_intro_in_channel(
+        &self,
+        intro: Handle<AudioSource>,
+        loop_body: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: loop_body,
+                looped: true,
+                loop_region: None,
+                intro: Some(intro),
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Fade from the audio playing in the given channel to the given audio source
+    ///
+    /// See [`Audio::crossfade`] for details.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.crossfade_in_channel(
+    ///         asset_server.load("next_track.ogg"),
+    ///         Duration::from_secs(3),
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn crossfade_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        duration: Duration,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.crossfade_with_curve_in_channel(
+            audio_source,
+            duration,
+            FadeCurve::EqualPower,
+            channel_id,
+        )
+    }
+
+    /// Fade from the audio playing in the given channel to the given audio source
+    /// using the given fade curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.crossfade_with_curve_in_channel(
+    ///         asset_server.load("next_track.ogg"),
+    ///         Duration::from_secs(3),
+    ///         FadeCurve::Logarithmic,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn crossfade_with_curve_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                crossfade: Some((duration, curve)),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play the given audio sources one after another in the given channel
+    ///
+    /// See [`Audio::play_queue`] for details.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_queue_in_channel(
+    ///         vec![asset_server.load("track_1.ogg"), asset_server.load("track_2.ogg")],
+    ///         false,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_queue_in_channel(
+        &self,
+        audio_sources: Vec<Handle<AudioSource>>,
+        looped: bool,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PlayQueue(audio_sources, looped),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Add an audio source to the end of the queue of the given channel
+    ///
+    /// If the queue already finished, the audio source starts playing right away.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.queue_next_in_channel(asset_server.load("track_3.ogg"), &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn queue_next_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::QueueNext(audio_source),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Clear the queue of the given channel
+    ///
+    /// The currently playing audio source keeps playing, but no further audio is started.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.clear_queue_in_channel(&AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn clear_queue_in_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::ClearQueue,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Play one of the given audio sources, chosen at random, in the given channel
+    ///
+    /// Returns `None` if no audio source was given.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_random_in_channel(
+    ///         &[asset_server.load("hit_1.mp3"), asset_server.load("hit_2.mp3")],
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_random_in_channel(
+        &self,
+        audio_sources: &[Handle<AudioSource>],
+        channel_id: &AudioChannel,
+    ) -> Option<InstanceHandle> {
+        let audio_source = self.pick_random(audio_sources)?;
+        Some(self.play_in_channel(audio_source, channel_id))
+    }
+
+    /// Play one of the given audio sources in the given channel, chosen by weight
+    ///
+    /// See [`Audio::play_random_weighted`] for how the audio source is chosen.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_random_weighted_in_channel(
+    ///         &[
+    ///             (asset_server.load("hit_1.mp3"), 3.0),
+    ///             (asset_server.load("hit_critical.mp3"), 0.5),
+    ///         ],
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_random_weighted_in_channel(
+        &self,
+        audio_sources: &[(Handle<AudioSource>, f32)],
+        channel_id: &AudioChannel,
+    ) -> Option<InstanceHandle> {
+        let audio_source = self.pick_random_weighted(audio_sources)?;
+        Some(self.play_in_channel(audio_source, channel_id))
+    }
+
+    /// Play audio in the given channel with a random pitch offset
+    ///
+    /// The pitch is shifted by a random amount of up to `semitones` in either direction.
+    /// The variation only applies to this instance and does not change the channel pitch.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_pitch_variation_in_channel(
+    ///         asset_server.load("hit.mp3"),
+    ///         1.5,
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_with_pitch_variation_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        semitones: f32,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                pitch_variation: Some(semitones),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel, ignoring further plays of it for the given cooldown
+    ///
+    /// Plays of the audio in the channel with the same cooldown that are started within
+    /// the cooldown after this one are skipped.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_cooldown_in_channel(
+    ///         asset_server.load("coin.mp3"),
+    ///         Duration::from_millis(50),
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_with_cooldown_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        cooldown: Duration,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::new(cooldown.as_secs_f64()),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play audio backwards in the given channel
+    ///
+    /// The volume and panning of the channel apply as for any other instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_reversed_in_channel(
+    ///         asset_server.load("rewind.mp3"),
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_reversed_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: true,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play looped audio backwards in the given channel
+    ///
+    /// Reversed instances repeat the looped part from its end back to its start.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_reversed_in_channel(
+    ///         asset_server.load("rewind.mp3"),
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_reversed_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: true,
+                repeats: 1,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play audio the given number of times in a row in the given channel
+    ///
+    /// See [`Audio::play_repeated`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_repeated_in_channel(
+    ///         asset_server.load("knock.ogg"),
+    ///         3,
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_repeated_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        times: u32,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        if times == 0 {
+            return InstanceHandle::new(
+                self.next_instance_id.fetch_add(1, Ordering::Relaxed),
+                channel_id,
+            );
+        }
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: times,
+            },
+            PlayOptions::default(),
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel on the next beat interval of its metronome
+    ///
+    /// See [`Audio::play_quantized`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn add_drums(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_quantized_in_channel(
+    ///         asset_server.load("drums.ogg"),
+    ///         4.0,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_quantized_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        interval: f64,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                quantize: Some(interval),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play looped audio in the given channel on the next beat interval of its metronome
+    ///
+    /// See [`Audio::play_quantized`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn add_bass(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_quantized_in_channel(
+    ///         asset_server.load("bass.ogg"),
+    ///         4.0,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_quantized_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        interval: f64,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                quantize: Some(interval),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play several audio sources in the given channel, starting on the same audio frame
+    ///
+    /// See [`Audio::play_synced`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn start_music(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_synced_in_channel(
+    ///         &[
+    ///             asset_server.load("music/drums.ogg"),
+    ///             asset_server.load("music/strings.ogg"),
+    ///         ],
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_synced_in_channel(
+        &self,
+        audio_sources: &[Handle<AudioSource>],
+        channel_id: &AudioChannel,
+    ) -> Vec<InstanceHandle> {
+        self.queue_play_synced(audio_sources, false, channel_id)
+    }
+
+    /// Loop several audio sources in the given channel, starting on the same audio frame
+    ///
+    /// See [`Audio::play_looped_synced`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn start_music(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_synced_in_channel(
+    ///         &[
+    ///             asset_server.load("music/drums.ogg"),
+    ///             asset_server.load("music/strings.ogg"),
+    ///         ],
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_synced_in_channel(
+        &self,
+        audio_sources: &[Handle<AudioSource>],
+        channel_id: &AudioChannel,
+    ) -> Vec<InstanceHandle> {
+        self.queue_play_synced(audio_sources, true, channel_id)
+    }
+
+    /// Play audio in the given channel unless it is already playing there
+    ///
+    /// Paused instances of the audio also count as playing. If the audio is skipped,
+    /// the returned handle does not refer to any instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_if_not_playing_in_channel(
+    ///         asset_server.load("alarm.mp3"),
+    ///         &AudioChannel::new("sfx".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_if_not_playing_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                skip_if_playing: true,
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play looped audio in the given channel unless it is already playing there
+    ///
+    /// Paused instances of the audio also count as playing. If the audio is skipped,
+    /// the returned handle does not refer to any instance.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_looped_if_not_playing_in_channel(
+    ///         asset_server.load("wind.ogg"),
+    ///         &AudioChannel::new("ambience".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_looped_if_not_playing_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: true,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                skip_if_playing: true,
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel and tag its [`AudioFinished`](crate::AudioFinished) event
+    ///
+    /// See [`Audio::play_with_on_finish`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_on_finish_in_channel(
+    ///         asset_server.load("line_1.ogg"),
+    ///         1,
+    ///         &AudioChannel::new("dialog".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_with_on_finish_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        on_finish: u64,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: false,
+                loop_region: None,
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                on_finish: Some(on_finish),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Play audio in the given channel with the given settings
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, PlaySettings};
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play_with_in_channel(
+    ///         asset_server.load("rain.ogg"),
+    ///         PlaySettings::new().volume(0.3).looped(),
+    ///         &AudioChannel::new("ambience".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_with_in_channel(
+        &self,
+        audio_source: Handle<AudioSource>,
+        settings: PlaySettings,
+        channel_id: &AudioChannel,
+    ) -> InstanceHandle {
+        self.queue_play(
+            PlayAudioSettings {
+                source: audio_source,
+                looped: settings.looped,
+                loop_region: settings
+                    .loop_region
+                    .map(|(loop_start, loop_end)| LoopRegion::new(loop_start, loop_end)),
+                intro: None,
+                cooldown: Cooldown::default(),
+                reversed: false,
+                repeats: 1,
+            },
+            PlayOptions {
+                start_position: settings.start_position,
+                play_settings: Some(settings),
+                ..Default::default()
+            },
+            channel_id,
+        )
+    }
+
+    /// Stream an audio file from disk in the given channel
+    ///
+    /// See [`Audio::play_streamed`] for the differences to loaded audio sources.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.play_streamed_in_channel("assets/soundtrack.ogg", &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn play_streamed_in_channel(&self, path: impl Into<PathBuf>, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PlayStreamed(path.into(), false),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Stream an audio file from disk in a loop in the given channel
+    ///
+    /// See [`Audio::play_streamed`] for the differences to loaded audio sources.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.play_streamed_looped_in_channel("assets/soundtrack.ogg", &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn play_streamed_looped_in_channel(
+        &self,
+        path: impl Into<PathBuf>,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PlayStreamed(path.into(), true),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Stop audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_channel(&AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn stop_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Stop,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Fade out and then stop audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_channel_with_fade(Duration::from_secs(2), &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn stop_channel_with_fade(&self, fade_duration: Duration, channel_id: &AudioChannel) {
+        self.stop_channel_with_fade_curve(fade_duration, FadeCurve::Linear, channel_id);
+    }
+
+    /// Fade out with the given curve and then stop audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_channel_with_fade_curve(
+    ///         Duration::from_secs(2),
+    ///         FadeCurve::Logarithmic,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn stop_channel_with_fade_curve(
+        &self,
+        fade_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::StopWithFade(curve.tween(fade_duration, false)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Pause audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_channel(&AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn pause_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Pause,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Fade out and then pause audio in the given channel
+    ///
+    /// The instances keep playing while they fade out, so they resume from the
+    /// position at the end of the fade.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_channel_with_fade(Duration::from_millis(500), &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn pause_channel_with_fade(&self, fade_duration: Duration, channel_id: &AudioChannel) {
+        self.pause_channel_with_fade_curve(fade_duration, FadeCurve::Linear, channel_id);
+    }
+
+    /// Fade out with the given curve and then pause audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.pause_channel_with_fade_curve(
+    ///         Duration::from_millis(500),
+    ///         FadeCurve::EqualPower,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn pause_channel_with_fade_curve(
+        &self,
+        fade_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PauseWithFade(curve.tween(fade_duration, false)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Pause the given channel if it is not paused, otherwise resume it
+    ///
+    /// A channel is paused from the moment it receives a pause command
+    /// until it receives a resume command.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn pause_button(audio: Res<Audio>) {
+    ///     audio.toggle_pause_channel(&AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn toggle_pause_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::TogglePause,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Resume audio in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_channel(&AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn resume_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Resume,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Resume audio in the given channel, fading it in
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_channel_with_fade(Duration::from_millis(500), &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn resume_channel_with_fade(&self, fade_duration: Duration, channel_id: &AudioChannel) {
+        self.resume_channel_with_fade_curve(fade_duration, FadeCurve::Linear, channel_id);
+    }
+
+    /// Resume audio in the given channel, fading it in with the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.resume_channel_with_fade_curve(
+    ///         Duration::from_millis(500),
+    ///         FadeCurve::EqualPower,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn resume_channel_with_fade_curve(
+        &self,
+        fade_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::ResumeWithFade(curve.tween(fade_duration, true)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Play all audio in the given channel from the beginning
+    ///
+    /// See [`Audio::restart`] for what happens if nothing is playing anymore.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.restart_channel(&AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn restart_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::Restart,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Jump to a position in all audio in the given channel
+    ///
+    /// See [`Audio::seek_to`] for how positions are limited.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.seek_to_in_channel(12.5, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn seek_to_in_channel(&self, position: impl IntoSeconds, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SeekTo(position.into_seconds()),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Move the position of all audio in the given channel by the given time
+    ///
+    /// See [`Audio::seek_by`] for details.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.seek_by_in_channel(10.0, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn seek_by_in_channel(&self, offset: impl IntoSeconds, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SeekBy(offset.into_seconds()),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set the volume, panning and pitch of the given channel at once
+    ///
+    /// The settings are kept for the channel, even if nothing plays in it yet. All audio
+    /// played in the channel afterwards starts with them. See
+    /// [`Audio::set_volume_in_channel`], [`Audio::set_panning_in_channel`] and
+    /// [`Audio::set_pitch_in_channel`] for the values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn setup_mix(audio: Res<Audio>) {
+    ///     audio.configure_channel(0.4, 0.5, 1.0, &AudioChannel::new("music".to_owned()));
+    ///     audio.configure_channel(0.8, 0.3, 1.1, &AudioChannel::new("sfx".to_owned()));
+    /// }
+    /// ```
+    pub fn configure_channel(
+        &self,
+        volume: f32,
+        panning: f32,
+        pitch: f32,
+        channel_id: &AudioChannel,
+    ) {
+        self.set_volume_in_channel(volume, channel_id);
+        self.set_panning_in_channel(panning, channel_id);
+        self.set_pitch_in_channel(pitch, channel_id);
+    }
+
+    /// Get the volume, panning and pitch of all configured channels
+    ///
+    /// Like [`Audio::channel_volume`], the snapshot contains the latest requested settings,
+    /// even if the audio system did not apply them yet. See [`AudioMixerSnapshot`].
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     let music = AudioChannel::new("music".to_owned());
+    ///     audio.set_volume_in_channel(0.3, &music);
+    ///     assert_eq!(audio.snapshot().channels[&music].volume, 0.3);
+    /// }
+    /// ```
+    pub fn snapshot(&self) -> AudioMixerSnapshot {
+        AudioMixerSnapshot {
+            channels: self.channel_settings.read().clone(),
+        }
+    }
+
+    /// Apply the volume, panning and pitch of all channels in the snapshot
+    ///
+    /// Configured channels that are missing from the snapshot are reset to the default settings.
+    /// Restoring an empty snapshot resets all channels.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerSnapshot};
+    ///
+    /// fn reset_mix(audio: Res<Audio>) {
+    ///     audio.restore(&AudioMixerSnapshot::default());
+    /// }
+    /// ```
+    pub fn restore(&self, snapshot: &AudioMixerSnapshot) {
+        let missing: Vec<AudioChannel> = self
+            .channel_settings
+            .read()
+            .keys()
+            .filter(|channel_id| !snapshot.channels.contains_key(*channel_id))
+            .cloned()
+            .collect();
+        for channel_id in missing {
+            let defaults = ChannelMixerSettings::default();
+            self.configure_channel(
+                defaults.volume,
+                defaults.panning,
+                defaults.pitch,
+                &channel_id,
+            );
+        }
+        for (channel_id, settings) in snapshot.channels.iter() {
+            self.configure_channel(
+                settings.volume,
+                settings.panning,
+                settings.pitch,
+                channel_id,
+            );
+        }
+    }
+
+    /// Set the volume of the given channel
+    ///
+    /// The default value is 1. The channel volume is multiplied with the
+    /// volume of each instance in the channel, see [`Audio::set_instance_volume`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_channel_volume(0.5, &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn set_channel_volume(&self, volume: f32, channel_id: &AudioChannel) {
+        self.update_channel_settings(channel_id, |settings| settings.volume = volume);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetVolume(volume),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set the volume for the given channel
+    ///
+    /// The same as [`Audio::set_channel_volume`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_in_channel(0.5, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_volume_in_channel(&self, volume: f32, channel_id: &AudioChannel) {
+        self.set_channel_volume(volume, channel_id);
+    }
+
+    /// Mute or unmute the given channel
+    ///
+    /// Muting keeps the channel volume, so it is restored when unmuting.
+    /// Audio played in a muted channel starts silent.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_muted_in_channel(true, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_muted_in_channel(&self, muted: bool, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetMuted(muted),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Smoothly change the volume for the given channel over the given duration
+    ///
+    /// Audio played during the transition starts at the new volume.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_with_tween_in_channel(0.2, Duration::from_secs(1), &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_volume_with_tween_in_channel(
+        &self,
+        volume: f32,
+        tween_duration: Duration,
+        channel_id: &AudioChannel,
+    ) {
+        self.set_volume_with_tween_curve_in_channel(
+            volume,
+            tween_duration,
+            FadeCurve::Linear,
+            channel_id,
+        );
+    }
+
+    /// Smoothly change the volume for the given channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_with_tween_curve_in_channel(
+    ///         0.2,
+    ///         Duration::from_secs(4),
+    ///         FadeCurve::Logarithmic,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_volume_with_tween_curve_in_channel(
+        &self,
+        volume: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        let rising = volume >= self.channel_volume(channel_id);
+        self.update_channel_settings(channel_id, |settings| settings.volume = volume);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetVolumeTween(volume, curve.tween(tween_duration, rising)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set the volume for the given channel in decibels
+    ///
+    /// The default value is 0 dB. Very low values and negative infinity are silent.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_volume_db_in_channel(-6.0, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_volume_db_in_channel(&self, decibels: f32, channel_id: &AudioChannel) {
+        self.set_volume_in_channel(decibels_to_amplitude(decibels), channel_id);
+    }
+
+    /// Set panning for the given channel
+    ///
+    /// The default value is 0.5
+    /// Values up to 1 pan to the right
+    /// Values down to 0 pan to the left
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_panning_in_channel(0.9, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_panning_in_channel(&self, panning: f32, channel_id: &AudioChannel) {
+        self.update_channel_settings(channel_id, |settings| settings.panning = panning);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPanning(panning),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Smoothly change the panning for the given channel over the given duration
+    ///
+    /// [`Audio::channel_panning`] returns the target panning right away.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn fly_by(audio: Res<Audio>) {
+    ///     audio.set_panning_with_tween_in_channel(
+    ///         1.0,
+    ///         Duration::from_secs(1),
+    ///         &AudioChannel::new("plane".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_panning_with_tween_in_channel(
+        &self,
+        panning: f32,
+        tween_duration: Duration,
+        channel_id: &AudioChannel,
+    ) {
+        self.set_panning_with_tween_curve_in_channel(
+            panning,
+            tween_duration,
+            FadeCurve::Linear,
+            channel_id,
+        );
+    }
+
+    /// Smoothly change the panning for the given channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn fly_by(audio: Res<Audio>) {
+    ///     audio.set_panning_with_tween_curve_in_channel(
+    ///         1.0,
+    ///         Duration::from_secs(1),
+    ///         FadeCurve::EqualPower,
+    ///         &AudioChannel::new("plane".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_panning_with_tween_curve_in_channel(
+        &self,
+        panning: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        let rising = panning >= self.channel_panning(channel_id);
+        self.update_channel_settings(channel_id, |settings| settings.panning = panning);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPanningTween(panning, curve.tween(tween_duration, rising)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set pitch for the given channel
+    ///
+    /// The default value is 1
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_pitch_in_channel(2.0, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_pitch_in_channel(&self, pitch: f32, channel_id: &AudioChannel) {
+        self.update_channel_settings(channel_id, |settings| settings.pitch = pitch);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPitch(pitch),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Smoothly change the pitch for the given channel over the given duration
+    ///
+    /// See [`Audio::set_pitch_with_tween`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn tape_stop(audio: Res<Audio>) {
+    ///     audio.set_pitch_with_tween_in_channel(
+    ///         0.1,
+    ///         Duration::from_secs(2),
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_pitch_with_tween_in_channel(
+        &self,
+        pitch: f32,
+        tween_duration: Duration,
+        channel_id: &AudioChannel,
+    ) {
+        self.set_pitch_with_tween_curve_in_channel(
+            pitch,
+            tween_duration,
+            FadeCurve::Linear,
+            channel_id,
+        );
+    }
+
+    /// Smoothly change the pitch for the given channel over the given duration
+    /// following the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn tape_stop(audio: Res<Audio>) {
+    ///     audio.set_pitch_with_tween_curve_in_channel(
+    ///         0.1,
+    ///         Duration::from_secs(2),
+    ///         FadeCurve::Logarithmic,
+    ///         &AudioChannel::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_pitch_with_tween_curve_in_channel(
+        &self,
+        pitch: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+        channel_id: &AudioChannel,
+    ) {
+        let rising = pitch >= self.channel_pitch(channel_id);
+        self.update_channel_settings(channel_id, |settings| settings.pitch = pitch);
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetPitchTween(pitch, curve.tween(tween_duration, rising)),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set pitch for the given channel as an offset in semitones
+    ///
+    /// 12 semitones are one octave. Negative and fractional values are supported.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_pitch_semitones_in_channel(7.0, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_pitch_semitones_in_channel(&self, semitones: f32, channel_id: &AudioChannel) {
+        self.set_pitch_in_channel(semitones_to_pitch(semitones), channel_id);
+    }
+
+    /// Set the stereo balance for the given channel
+    ///
+    /// See [`Audio::set_balance`] for the range of values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_balance_in_channel(0.8, &AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn set_balance_in_channel(&self, balance: f32, channel_id: &AudioChannel) {
+        self.set_panning_in_channel(balance_to_panning(balance), channel_id);
+    }
+
+    /// Set the volume of a single instance
+    ///
+    /// The default value is 1. The effective volume of the instance is this
+    /// value multiplied with the volume of its channel.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let instance = audio.play(asset_server.load("audio.mp3"));
+    ///     audio.set_instance_volume(0.5, &instance);
+    /// }
+    /// ```
+    pub fn set_instance_volume(&self, volume: f32, instance: &InstanceHandle) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetInstanceVolume(instance.id, volume),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// Set the panning of a single instance
+    ///
+    /// The default value is the panning of the instance's channel.
+    /// Changing the panning of the channel afterwards overrides this value.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let instance = audio.play(asset_server.load("audio.mp3"));
+    ///     audio.set_instance_panning(0.2, &instance);
+    /// }
+    /// ```
+    pub fn set_instance_panning(&self, panning: f32, instance: &InstanceHandle) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetInstancePanning(instance.id, panning),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// Set the attenuation and panning of an instance from its emitter
+    ///
+    /// The attenuation is multiplied with the volume of the instance instead of replacing it.
+    pub(crate) fn set_instance_spatial(
+        &self,
+        volume: f32,
+        panning: f32,
+        instance: &InstanceHandle,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetInstanceSpatial(instance.id, volume, panning),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// Stop a single instance
+    ///
+    /// The other instances in its channel keep playing.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let instance = audio.play(asset_server.load("audio.mp3"));
+    ///     audio.stop_instance(&instance);
+    /// }
+    /// ```
+    pub fn stop_instance(&self, instance: &InstanceHandle) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::StopInstance(instance.id),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// Pause a single instance
+    ///
+    /// The other instances in its channel keep playing. Instances that already
+    /// finished are ignored.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let instance = audio.play(asset_server.load("audio.mp3"));
+    ///     audio.pause_instance(&instance);
+    /// }
+    /// ```
+    pub fn pause_instance(&self, instance: &InstanceHandle) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::PauseInstance(instance.id),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// Resume a single paused instance
+    ///
+    /// This also resumes instances that were paused together with their channel.
+    /// Instances that already finished are ignored.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, InstanceHandle};
+    ///
+    /// fn my_system(audio: Res<Audio>, instance: Res<InstanceHandle>) {
+    ///     audio.resume_instance(&instance);
+    /// }
+    /// ```
+    pub fn resume_instance(&self, instance: &InstanceHandle) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::ResumeInstance(instance.id),
+            instance.channel.clone(),
+        ));
+    }
+
+    /// The latest instance that was played with the given name in the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     if let Some(engine) = audio.named_instance("engine_loop") {
+    ///         audio.set_instance_volume(0.5, &engine);
+    ///     }
+    /// }
+    /// ```
+    pub fn named_instance(&self, name: &str) -> Option<InstanceHandle> {
+        self.named_instance_in_channel(name, &self.default_channel())
+    }
+
+    /// The latest instance that was played with the given name in the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     let engine =
+    ///         audio.named_instance_in_channel("engine_loop", &AudioChannel::new("vehicles".to_owned()));
+    /// }
+    /// ```
+    pub fn named_instance_in_channel(
+        &self,
+        name: &str,
+        channel_id: &AudioChannel,
+    ) -> Option<InstanceHandle> {
+        self.named_instances
+            .read()
+            .get(&(channel_id.clone(), name.to_owned()))
+            .cloned()
+    }
+
+    /// Stop the instance with the given name in the default channel
+    ///
+    /// Nothing happens if no instance was played with the name.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_named("engine_loop");
+    /// }
+    /// ```
+    pub fn stop_named(&self, name: &str) {
+        self.stop_named_in_channel(name, &self.default_channel());
+    }
+
+    /// Stop the instance with the given name in the given channel
+    ///
+    /// Nothing happens if no instance was played with the name.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_named_in_channel("engine_loop", &AudioChannel::new("vehicles".to_owned()));
+    /// }
+    /// ```
+    pub fn stop_named_in_channel(&self, name: &str, channel_id: &AudioChannel) {
+        if let Some(instance) = self.named_instance_in_channel(name, channel_id) {
+            self.stop_instance(&instance);
+        }
+    }
+
+    /// Set the volume of the instance with the given name in the default channel
+    ///
+    /// See [`Audio::set_instance_volume`] for the range of values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_named_volume(0.3, "engine_loop");
+    /// }
+    /// ```
+    pub fn set_named_volume(&self, volume: f32, name: &str) {
+        self.set_named_volume_in_channel(volume, name, &self.default_channel());
+    }
+
+    /// Set the volume of the instance with the given name in the given channel
+    ///
+    /// See [`Audio::set_instance_volume`] for the range of values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_named_volume_in_channel(
+    ///         0.3,
+    ///         "engine_loop",
+    ///         &AudioChannel::new("vehicles".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_named_volume_in_channel(&self, volume: f32, name: &str, channel_id: &AudioChannel) {
+        if let Some(instance) = self.named_instance_in_channel(name, channel_id) {
+            self.set_instance_volume(volume, &instance);
+        }
+    }
+
+    /// Set the panning of the instance with the given name in the default channel
+    ///
+    /// See [`Audio::set_instance_panning`] for the range of values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_named_panning(0.2, "engine_loop");
+    /// }
+    /// ```
+    pub fn set_named_panning(&self, panning: f32, name: &str) {
+        self.set_named_panning_in_channel(panning, name, &self.default_channel());
+    }
+
+    /// Set the panning of the instance with the given name in the given channel
+    ///
+    /// See [`Audio::set_instance_panning`] for the range of values.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_named_panning_in_channel(
+    ///         0.2,
+    ///         "engine_loop",
+    ///         &AudioChannel::new("vehicles".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_named_panning_in_channel(
+        &self,
+        panning: f32,
+        name: &str,
+        channel_id: &AudioChannel,
+    ) {
+        if let Some(instance) = self.named_instance_in_channel(name, channel_id) {
+            self.set_instance_panning(panning, &instance);
+        }
+    }
+
+    /// Free the memory that Kira uses for the given audio source
+    ///
+    /// All instances playing the source, in any channel, are stopped.
+    /// Playing the source again afterwards loads it into Kira again.
+    /// Removing the source from `Assets<AudioSource>` or modifying it unloads it automatically.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioSource};
+    ///
+    /// fn leave_level(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let level_music: Handle<AudioSource> = asset_server.load("level_music.mp3");
+    ///     audio.unload(&level_music);
+    /// }
+    /// ```
+    pub fn unload(&self, audio_source: &Handle<AudioSource>) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::Unload(
+                audio_source.clone(),
+            )));
+    }
+
+    /// Load the given audio sources into Kira ahead of their first play
+    ///
+    /// Kira needs the audio data of a source before playing it, so the first play
+    /// of a source can take noticeably longer than later ones. Preloading during a
+    /// loading screen avoids this. Sources that are still loading are added to Kira
+    /// as soon as they finished loading.
+    ///
+    /// The asset loaders decode audio sources in parallel on Bevy's IO task pool, without
+    /// blocking the main thread. Adding them to Kira happens on the main thread, so it can
+    /// be spread over several frames with
+    /// [`AudioSettings::max_preloads_per_frame`](crate::AudioSettings::max_preloads_per_frame).
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn loading_screen(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.preload(&[
+    ///         asset_server.load("gunshot.mp3"),
+    ///         asset_server.load("explosion.mp3"),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn preload(&self, audio_sources: &[Handle<AudioSource>]) {
+        self.commands
+            .write()
+            .push_back(QueuedAudioCommand::Global(GlobalAudioCommands::Preload(
+                audio_sources.to_vec(),
+            )));
+    }
+
+    /// Automatically lower the volume of a channel while audio is playing in another channel
+    ///
+    /// While any instance in `trigger` is playing, the volume of `ducked` is reduced by `amount`
+    /// (0 keeps the volume, 1 silences the channel) over the `attack` duration. Once the trigger
+    /// channel is silent again, the volume is restored over the `release` duration.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.add_ducking(
+    ///         &AudioChannel::new("music".to_owned()),
+    ///         &AudioChannel::new("dialog".to_owned()),
+    ///         0.7,
+    ///         Duration::from_millis(200),
+    ///         Duration::from_secs(1),
+    ///     );
+    /// }
+    /// ```
+    pub fn add_ducking(
+        &self,
+        ducked: &AudioChannel,
+        trigger: &AudioChannel,
+        amount: f32,
+        attack: Duration,
+        release: Duration,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::AddDucking(
+                trigger.clone(),
+                DuckingSettings {
+                    amount: amount.clamp(0.0, 1.0) as f64,
+                    attack: Tween::linear(attack.as_secs_f64()),
+                    release: Tween::linear(release.as_secs_f64()),
+                },
+            ),
+            ducked.clone(),
+        ));
+    }
+
+    /// Stop ducking a channel for the given trigger channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.remove_ducking(
+    ///         &AudioChannel::new("music".to_owned()),
+    ///         &AudioChannel::new("dialog".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn remove_ducking(&self, ducked: &AudioChannel, trigger: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::RemoveDucking(trigger.clone()),
+            ducked.clone(),
+        ));
+    }
+
+    /// Route all audio of a channel through the given mixer track
+    ///
+    /// Audio that is already playing in the channel stays on its previous track.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, AudioMixerTrack};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_channel_track(
+    ///         &AudioChannel::new("music".to_owned()),
+    ///         &AudioMixerTrack::new("music".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_channel_track(&self, channel_id: &AudioChannel, track: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetTrack(track.clone()),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Limit the number of instances that play at the same time in the default channel
+    ///
+    /// When a new instance would exceed the limit, the voice stealing policy decides
+    /// which instance is stopped or whether the new one is dropped.
+    /// Instances that are fading out do not count towards the limit.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, VoiceStealing};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_max_voices(4, VoiceStealing::StealQuietest);
+    /// }
+    /// ```
+    pub fn set_max_voices(&self, max_voices: usize, stealing: VoiceStealing) {
+        self.set_max_voices_in_channel(max_voices, stealing, &self.default_channel());
+    }
+
+    /// Limit the number of instances that play at the same time in the given channel
+    ///
+    /// See [`Audio::set_max_voices`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel, VoiceStealing};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_max_voices_in_channel(
+    ///         8,
+    ///         VoiceStealing::StealOldest,
+    ///         &AudioChannel::new("footsteps".to_owned()),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_max_voices_in_channel(
+        &self,
+        max_voices: usize,
+        stealing: VoiceStealing,
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetMaxVoices(Some((max_voices, stealing))),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Remove the limit of simultaneous instances from the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.remove_max_voices();
+    /// }
+    /// ```
+    pub fn remove_max_voices(&self) {
+        self.remove_max_voices_in_channel(&self.default_channel());
+    }
+
+    /// Remove the limit of simultaneous instances from the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.remove_max_voices_in_channel(&AudioChannel::new("footsteps".to_owned()));
+    /// }
+    /// ```
+    pub fn remove_max_voices_in_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetMaxVoices(None),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Start a metronome for the default channel
+    ///
+    /// The metronome ticks at the given tempo in beats per minute. Every time one of the
+    /// intervals (in beats) passes, an [`AudioBeat`](crate::AudioBeat) event is sent.
+    /// A metronome that is already running in the channel is replaced.
+    ///
+    /// The metronome is advanced by the audio backend. Starting it right after playing
+    /// the music starts both on the same audio frame, so the beats never drift apart
+    /// from the music.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn start_song(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     audio.play(asset_server.load("song.ogg"));
+    ///     audio.start_metronome(128.0, &[1.0, 4.0]);
+    /// }
+    /// ```
+    pub fn start_metronome(&self, tempo: f64, intervals: &[f64]) {
+        self.start_metronome_in_channel(tempo, intervals, &self.default_channel());
+    }
+
+    /// Start a metronome for the given channel
+    ///
+    /// See [`Audio::start_metronome`] for details.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn start_song(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+    ///     let channel = AudioChannel::new("music".to_owned());
+    ///     audio.play_in_channel(asset_server.load("song.ogg"), &channel);
+    ///     audio.start_metronome_in_channel(128.0, &[1.0], &channel);
+    /// }
+    /// ```
+    pub fn start_metronome_in_channel(
+        &self,
+        tempo: f64,
+        intervals: &[f64],
+        channel_id: &AudioChannel,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::StartMetronome(tempo, intervals.to_vec()),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Stop the metronome of the default channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_metronome();
+    /// }
+    /// ```
+    pub fn stop_metronome(&self) {
+        self.stop_metronome_in_channel(&self.default_channel());
+    }
+
+    /// Stop the metronome of the given channel
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.stop_metronome_in_channel(&AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn stop_metronome_in_channel(&self, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::StopMetronome,
+            channel_id.clone(),
+        ));
+    }
+
+    /// Change the tempo of the default channel's metronome in beats per minute
+    ///
+    /// The beats that already passed keep their count.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_metronome_tempo(140.0);
+    /// }
+    /// ```
+    pub fn set_metronome_tempo(&self, tempo: f64) {
+        self.set_metronome_tempo_in_channel(tempo, &self.default_channel());
+    }
+
+    /// Change the tempo of the given channel's metronome in beats per minute
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioChannel};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_metronome_tempo_in_channel(140.0, &AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn set_metronome_tempo_in_channel(&self, tempo: f64, channel_id: &AudioChannel) {
+        self.commands.write().push_back(QueuedAudioCommand::Channel(
+            AudioCommands::SetMetronomeTempo(tempo),
+            channel_id.clone(),
+        ));
+    }
+
+    /// Set the volume of a mixer track
+    ///
+    /// The track volume scales the mixed audio of all channels on the track, on top of their
+    /// channel volumes.
+    /// The default value is 1.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_track_volume(0.5, &AudioMixerTrack::new("sfx".to_owned()));
+    /// }
+    /// ```
+    pub fn set_track_volume(&self, volume: f32, track: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetTrackVolume(track.clone(), volume),
+        ));
+    }
+
+    /// Set the volume of all audio
+    ///
+    /// The master volume is applied on the master track after all channels and mixer
+    /// tracks, and before the master limiter. The volumes of the channels are not
+    /// changed. The default value is 1.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_master_volume(0.5);
+    /// }
+    /// ```
+    pub fn set_master_volume(&self, volume: f32) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetMasterVolume(volume, None),
+        ));
+    }
+
+    /// Smoothly change the volume of all audio over the given duration
+    ///
+    /// See [`Audio::set_master_volume`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_master_volume_with_tween(0.2, Duration::from_millis(500));
+    /// }
+    /// ```
+    pub fn set_master_volume_with_tween(&self, volume: f32, tween_duration: Duration) {
+        self.set_master_volume_with_tween_curve(volume, tween_duration, FadeCurve::Linear);
+    }
+
+    /// Smoothly change the volume of all audio over the given duration using the given curve
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, FadeCurve};
+    /// # use std::time::Duration;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_master_volume_with_tween_curve(0.2, Duration::from_secs(2), FadeCurve::Logarithmic);
+    /// }
+    /// ```
+    pub fn set_master_volume_with_tween_curve(
+        &self,
+        volume: f32,
+        tween_duration: Duration,
+        curve: FadeCurve,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetMasterVolume(volume, Some(curve.tween(tween_duration, true))),
+        ));
+    }
+
+    /// Add reverb to a mixer track or change the settings of its reverb
+    ///
+    /// All channels on the track send their audio through the reverb.
+    /// Changing the settings of an active reverb keeps its tail ringing out.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack, ReverbSettings};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_reverb(
+    ///         &AudioMixerTrack::new("environment".to_owned()),
+    ///         ReverbSettings::default(),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_reverb(&self, track: &AudioMixerTrack, settings: ReverbSettings) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetReverb(track.clone(), settings),
+        ));
+    }
+
+    /// Remove the reverb from a mixer track
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    ///
+    /// fn leave_cave(audio: Res<Audio>) {
+    ///     audio.disable_reverb(&AudioMixerTrack::new("environment".to_owned()));
+    /// }
+    /// ```
+    pub fn disable_reverb(&self, track: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::RemoveReverb(track.clone()),
+        ));
+    }
+
+    /// Compress a mixer track based on the level of another one
+    ///
+    /// While the audio of the `key` track is louder than the threshold, the `compressed`
+    /// track gets quieter. Unlike [`Audio::add_ducking`], this follows the loudness of
+    /// the key instead of only whether it plays, which gives the "pumping" duck of
+    /// music under voices or a kick drum. A sidechain that already compresses the track
+    /// is replaced.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack, SidechainSettings};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.add_sidechain(
+    ///         &AudioMixerTrack::new("music".to_owned()),
+    ///         &AudioMixerTrack::new("voices".to_owned()),
+    ///         SidechainSettings::default(),
+    ///     );
+    /// }
+    /// ```
+    pub fn add_sidechain(
+        &self,
+        compressed: &AudioMixerTrack,
+        key: &AudioMixerTrack,
+        settings: SidechainSettings,
+    ) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::AddSidechain(compressed.clone(), key.clone(), settings),
+        ));
+    }
+
+    /// Remove the sidechain compressor from a mixer track
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.remove_sidechain(&AudioMixerTrack::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn remove_sidechain(&self, compressed: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::RemoveSidechain(compressed.clone()),
+        ));
+    }
+
+    /// Limit the volume of the whole output to prevent clipping
+    ///
+    /// All channels and mixer tracks play through the limiter. Changing the settings of
+    /// an active limiter keeps its current gain reduction.
+    /// The limiter can also be enabled from the start with
+    /// [`AudioSettings::master_limiter`](crate::AudioSettings::master_limiter).
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, LimiterSettings};
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.set_master_limiter(LimiterSettings::default());
+    /// }
+    /// ```
+    pub fn set_master_limiter(&self, settings: LimiterSettings) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetMasterLimiter(settings),
+        ));
+    }
+
+    /// Remove the limiter from the output
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::Audio;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     audio.disable_master_limiter();
+    /// }
+    /// ```
+    pub fn disable_master_limiter(&self) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::RemoveMasterLimiter,
+        ));
+    }
+
+    /// Filter the audio of a mixer track
+    ///
+    /// The cutoff frequency is given in hertz. The resonance goes from 0 to 1 and
+    /// makes the filter ring around the cutoff frequency.
+    /// Calling this again with the same mode changes the active filter.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack, FilterMode};
+    ///
+    /// fn behind_wall(audio: Res<Audio>) {
+    ///     audio.set_filter(
+    ///         &AudioMixerTrack::new("sfx".to_owned()),
+    ///         FilterMode::LowPass,
+    ///         1200.0,
+    ///         0.0,
+    ///     );
+    /// }
+    /// ```
+    pub fn set_filter(
+        &self,
+        track: &AudioMixerTrack,
+        mode: FilterMode,
+        cutoff_hz: f32,
+        resonance: f32,
+    ) {
+        self.queue_filter(track, mode, cutoff_hz, resonance, None);
+    }
+
+    /// Smoothly sweep the filter of a mixer track to the given values over the given duration
+    ///
+    /// A track without a filter of this mode starts the sweep at a cutoff frequency
+    /// that leaves the audio unchanged.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack, FilterMode};
+    /// # use std::time::Duration;
+    ///
+    /// fn dive_underwater(audio: Res<Audio>) {
+    ///     audio.set_filter_with_tween(
+    ///         &AudioMixerTrack::new("environment".to_owned()),
+    ///         FilterMode::LowPass,
+    ///         600.0,
+    ///         0.3,
+    ///         Duration::from_millis(500),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_filter_with_tween(
+        &self,
+        track: &AudioMixerTrack,
+        mode: FilterMode,
+        cutoff_hz: f32,
+        resonance: f32,
+        tween_duration: Duration,
+    ) {
+        self.queue_filter(
+            track,
+            mode,
+            cutoff_hz,
+            resonance,
+            Some(Tween::linear(tween_duration.as_secs_f64())),
+        );
+    }
+
+    /// Remove the filter from a mixer track
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    ///
+    /// fn surface(audio: Res<Audio>) {
+    ///     audio.remove_filter(&AudioMixerTrack::new("environment".to_owned()));
+    /// }
+    /// ```
+    pub fn remove_filter(&self, track: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::RemoveFilter(track.clone()),
+        ));
+    }
+
+    fn queue_filter(
+        &self,
+        track: &AudioMixerTrack,
+        mode: FilterMode,
+        cutoff_hz: f32,
+        resonance: f32,
+        tween: Option<Tween>,
+    ) {
+        let settings = FilterSettings {
+            mode,
+            cutoff: cutoff_hz.max(0.0) as f64,
+            resonance: resonance.clamp(0.0, 1.0) as f64,
+        };
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetFilter(track.clone(), settings, tween),
+        ));
+    }
+
+    /// Shift the pitch of a mixer track by the given semitones without changing its speed
+    ///
+    /// [`Audio::set_pitch`] changes speed and pitch together, like a tape played faster
+    /// or slower. Combine both to change them independently: a pitch of 0.5 together with
+    /// a shift of 12 semitones plays at half speed with the original pitch. The shift
+    /// applies to all channels on the track and is limited to two octaves up or down.
+    /// Large shifts can sound grainy, and the track is delayed by up to 50 milliseconds.
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{pitch_to_semitones, Audio, AudioChannel, AudioMixerTrack};
+    ///
+    /// fn slow_motion(audio: Res<Audio>) {
+    ///     let channel = AudioChannel::new("world".to_owned());
+    ///     let track = AudioMixerTrack::new("world".to_owned());
+    ///     audio.set_channel_track(&channel, &track);
+    ///     audio.set_pitch_in_channel(0.5, &channel);
+    ///     audio.set_pitch_shift(&track, -pitch_to_semitones(0.5));
+    /// }
+    /// ```
+    pub fn set_pitch_shift(&self, track: &AudioMixerTrack, semitones: f32) {
+        self.queue_pitch_shift(track, semitones, None);
+    }
+
+    /// Smoothly change the pitch shift of a mixer track over the given duration
+    ///
+    /// A track without a pitch shift starts the transition unshifted.
+    /// See [`Audio::set_pitch_shift`].
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    /// # use std::time::Duration;
+    ///
+    /// fn power_down(audio: Res<Audio>) {
+    ///     audio.set_pitch_shift_with_tween(
+    ///         &AudioMixerTrack::new("engine".to_owned()),
+    ///         -7.0,
+    ///         Duration::from_secs(2),
+    ///     );
+    /// }
+    /// ```
+    pub fn set_pitch_shift_with_tween(
+        &self,
+        track: &AudioMixerTrack,
+        semitones: f32,
+        tween_duration: Duration,
+    ) {
+        self.queue_pitch_shift(
+            track,
+            semitones,
+            Some(Tween::linear(tween_duration.as_secs_f64())),
+        );
+    }
+
+    /// Remove the pitch shift from a mixer track
+    ///
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    ///
+    /// fn end_slow_motion(audio: Res<Audio>) {
+    ///     audio.remove_pitch_shift(&AudioMixerTrack::new("world".to_owned()));
+    /// }
+    /// ```
+    pub fn remove_pitch_shift(&self, track: &AudioMixerTrack) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::RemovePitchShift(track.clone()),
+        ));
+    }
+
+    fn queue_pitch_shift(&self, track: &AudioMixerTrack, semitones: f32, tween: Option<Tween>) {
+        self.commands.write().push_back(QueuedAudioCommand::Global(
+            GlobalAudioCommands::SetPitchShift(
+                track.clone(),
+                semitones.clamp(-24.0, 24.0) as f64,
+                tween,
+            ),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::HandleId;
+
+    fn sources(count: usize) -> Vec<Handle<AudioSource>> {
+        (0..count)
+            .map(|_| Handle::weak(HandleId::random::<AudioSource>()))
+            .collect()
+    }
+
+    /// The audio sources of the queued play commands, in order
+    fn queued_sources(audio: &Audio) -> Vec<Handle<AudioSource>> {
+        audio
+            .commands
+            .read()
+            .iter()
+            .filter_map(|command| match command {
+                QueuedAudioCommand::Channel(AudioCommands::Play(play_settings, _), _) => {
+                    Some(play_settings.source.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn random_plays(seed: u64, sources: &[Handle<AudioSource>]) -> Vec<Handle<AudioSource>> {
+        let audio = Audio::default();
+        audio.set_random_seed(seed);
+        for _ in 0..32 {
+            audio.play_random(sources);
+        }
+        queued_sources(&audio)
+    }
+
+    fn weighted_random_plays(
+        seed: u64,
+        sources: &[(Handle<AudioSource>, f32)],
+    ) -> Vec<Handle<AudioSource>> {
+        let audio = Audio::default();
+        audio.set_random_seed(seed);
+        for _ in 0..32 {
+            audio.play_random_weighted(sources);
+        }
+        queued_sources(&audio)
+    }
+
+    #[test]
+    fn same_seed_plays_the_same_random_sequence() {
+        let sources = sources(4);
+        let plays = random_plays(7, &sources);
+        assert_eq!(plays.len(), 32);
+        assert_eq!(plays, random_plays(7, &sources));
+        assert_ne!(plays, random_plays(8, &sources));
+    }
+
+    #[test]
+    fn same_seed_plays_the_same_weighted_random_sequence() {
+        let sources: Vec<_> = sources(3)
+            .into_iter()
+            .zip([5.0, 1.0, 2.0].iter().copied())
+            .collect();
+        let plays = weighted_random_plays(7, &sources);
+        assert_eq!(plays.len(), 32);
+        assert_eq!(plays, weighted_random_plays(7, &sources));
+        assert_ne!(plays, weighted_random_plays(8, &sources));
     }
 }
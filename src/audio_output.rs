@@ -1,262 +1,3558 @@
-use crate::audio::{Audio, AudioCommands, PlayAudioSettings};
+use crate::audio::{
+    Audio, AudioCommands, DuckingSettings, FilterSettings, GlobalAudioCommands, LoopRegion,
+    PlayAudioSettings, PlayInstanceSettings, QueuedAudioCommand,
+};
+use bevy::app::AppExit;
 use bevy::prelude::*;
 
 use crate::channel::AudioChannel;
-use crate::source::AudioSource;
+#[cfg(feature = "output_device")]
+use crate::device::DeviceOutput;
+use crate::error::AudioError;
+use crate::fade::FadeCurve;
+use crate::gain::Gain;
+use crate::instance::AudioFinished;
+use crate::limiter::{Limiter, LimiterSettings};
+use crate::meter::{Meter, MeterLevels};
+use crate::metronome::{AudioBeat, AudioClockState, ChannelMetronome, SYNC_INTERVAL};
+use crate::mixer::AudioMixerTrack;
+use crate::pitch_shift::PitchShifter;
+use crate::reverb::{Reverb, ReverbSettings};
+use crate::settings::AudioSettings;
+use crate::sidechain::{SidechainCompressor, SidechainDetector, SidechainSettings};
+use crate::source::{AudioSource, LoopSeam};
+use crate::state::{AudioState, ChannelPlaybackState, PlaybackState};
+use crate::stream::{FileStream, StreamControl};
+use crate::voices::VoiceStealing;
 use kira::arrangement::handle::ArrangementHandle;
-use kira::arrangement::{Arrangement, ArrangementSettings, SoundClip};
-use kira::instance::handle::InstanceHandle;
-use kira::instance::{PauseInstanceSettings, ResumeInstanceSettings, StopInstanceSettings};
+use kira::arrangement::{Arrangement, ArrangementSettings, LoopArrangementSettings, SoundClip};
+use kira::audio_stream::AudioStreamId;
+use kira::instance::handle::{InstanceHandle, InstanceHandleError};
+use kira::instance::{
+    InstanceSettings, InstanceState, PauseInstanceSettings, ResumeInstanceSettings,
+    StopInstanceSettings,
+};
+use kira::manager::error::AddArrangementError;
+#[cfg(feature = "headless")]
+use kira::manager::Backend;
 use kira::manager::{AudioManager, AudioManagerSettings};
+use kira::metronome::MetronomeSettings;
+use kira::mixer::effect::filter::Filter;
+use kira::mixer::effect::handle::EffectHandle;
+use kira::mixer::effect::EffectSettings;
+use kira::mixer::handle::TrackHandle;
+use kira::mixer::{TrackIndex, TrackSettings};
+use kira::parameter::handle::ParameterHandle;
+use kira::parameter::tween::Tween;
+use kira::parameter::{Mapping, ParameterSettings};
+use kira::sequence::{Sequence, SequenceInstanceSettings, SequenceSettings};
 use kira::sound::handle::SoundHandle;
-use std::collections::HashMap;
+use kira::{Tempo, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::atomic::AtomicU32;
+use std::sync::Arc;
+
+/// Kira keeps running an unfinished tween when a parameter is set without one.
+/// "Instant" parameter changes use a tween that is too short to be audible instead.
+const INSTANT_TWEEN_DURATION: f64 = 0.001;
+
+/// How fast the volume and panning of emitters follow their movement, in seconds
+///
+/// The spatial system sends new values every frame. Applying them directly would change
+/// the volume and panning of fast moving emitters in steps, which can be heard as
+/// zipper noise. Instead, they approach the values exponentially with this time constant.
+const SPATIAL_SMOOTHING: f64 = 0.05;
+
+fn instant_or(tween: Option<Tween>) -> Tween {
+    tween.unwrap_or_else(|| Tween::linear(INSTANT_TWEEN_DURATION))
+}
+
+/// Move the value the given part of the way to the target, ending exactly on the target
+fn approach(value: f64, target: f64, step: f64) -> f64 {
+    let next = value + (target - value) * step;
+    if (target - next).abs() < 1e-4 {
+        target
+    } else {
+        next
+    }
+}
+
+/// Drop setter commands that are overridden by a later one before anything else happens
+///
+/// Only the last value would be audible, so systems that set a value every frame
+/// do not pile up work for the audio system. Setters of different values do not
+/// depend on each other, so a run of them only keeps the last setter per value.
+/// Any other command ends the run, which keeps the order relative to plays and stops.
+fn coalesce_setters(commands: &mut VecDeque<QueuedAudioCommand>) {
+    let mut coalesced: VecDeque<QueuedAudioCommand> = VecDeque::new();
+    let mut run_start = 0;
+    for command in commands.drain(..) {
+        if !is_setter(&command) {
+            coalesced.push_back(command);
+            run_start = coalesced.len();
+            continue;
+        }
+        if let Some(index) =
+            (run_start..coalesced.len()).find(|&index| is_overridden(&coalesced[index], &command))
+        {
+            coalesced.remove(index);
+        }
+        coalesced.push_back(command);
+    }
+    *commands = coalesced;
+}
+
+fn is_setter(command: &QueuedAudioCommand) -> bool {
+    match command {
+        QueuedAudioCommand::Channel(command, _) => matches!(
+            command,
+            AudioCommands::SetVolume(_)
+                | AudioCommands::SetMuted(_)
+                | AudioCommands::SetPanning(_)
+                | AudioCommands::SetPitch(_)
+                | AudioCommands::SetInstanceVolume(..)
+                | AudioCommands::SetInstancePanning(..)
+                | AudioCommands::SetInstanceSpatial(..)
+        ),
+        QueuedAudioCommand::Global(command) => matches!(
+            command,
+            GlobalAudioCommands::SetTrackVolume(..) | GlobalAudioCommands::SetMasterVolume(_, None)
+        ),
+    }
+}
+
+/// Whether the next command sets the same value as the previous one
+fn is_overridden(previous: &QueuedAudioCommand, next: &QueuedAudioCommand) -> bool {
+    match (previous, next) {
+        (
+            QueuedAudioCommand::Channel(previous, previous_channel),
+            QueuedAudioCommand::Channel(next, next_channel),
+        ) => {
+            previous_channel == next_channel
+                && match (previous, next) {
+                    (AudioCommands::SetVolume(_), AudioCommands::SetVolume(_))
+                    | (AudioCommands::SetMuted(_), AudioCommands::SetMuted(_))
+                    | (AudioCommands::SetPanning(_), AudioCommands::SetPanning(_))
+                    | (AudioCommands::SetPitch(_), AudioCommands::SetPitch(_)) => true,
+                    (
+                        AudioCommands::SetInstanceVolume(previous, _),
+                        AudioCommands::SetInstanceVolume(next, _),
+                    )
+                    | (
+                        AudioCommands::SetInstancePanning(previous, _),
+                        AudioCommands::SetInstancePanning(next, _),
+                    )
+                    | (
+                        AudioCommands::SetInstanceSpatial(previous, ..),
+                        AudioCommands::SetInstanceSpatial(next, ..),
+                    ) => previous == next,
+                    _ => false,
+                }
+        }
+        (QueuedAudioCommand::Global(previous), QueuedAudioCommand::Global(next)) => {
+            match (previous, next) {
+                (
+                    GlobalAudioCommands::SetMasterVolume(_, None),
+                    GlobalAudioCommands::SetMasterVolume(_, None),
+                ) => true,
+                (
+                    GlobalAudioCommands::SetTrackVolume(previous, _),
+                    GlobalAudioCommands::SetTrackVolume(next, _),
+                ) => previous == next,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The sample rate of Kira's backend when it runs without an audio device
+#[cfg(feature = "headless")]
+const HEADLESS_SAMPLE_RATE: f64 = 48000.0;
 
 pub struct AudioOutput {
-    manager: AudioManager,
+    manager: Option<AudioManager>,
     sounds: HashMap<Handle<AudioSource>, SoundHandle>,
-    arrangements: HashMap<PlayAudioSettings, ArrangementHandle>,
-    instances: HashMap<AudioChannel, Vec<InstanceHandle>>,
+    /// The seam sounds of looped audio sources, see [`LoopSeam`]
+    seam_sounds: HashMap<Handle<AudioSource>, SoundHandle>,
+    arrangements: HashMap<(PlayAudioSettings, AudioChannel), ArrangementHandle>,
+    /// When each cached arrangement was last played, counted in plays
+    arrangement_last_played: HashMap<(PlayAudioSettings, AudioChannel), u64>,
+    arrangement_plays: u64,
+    /// The remaining cooldown of arrangements in seconds
+    ///
+    /// Kira silently drops plays of cooling down arrangements while their instance handles
+    /// keep reporting them as playing, so the cooldown is applied before playing instead.
+    cooldowns: HashMap<(PlayAudioSettings, AudioChannel), f64>,
+    instances: HashMap<AudioChannel, Vec<AudioInstance>>,
+    streams: HashMap<AudioChannel, Vec<(AudioStreamId, StreamControl)>>,
     channels: HashMap<AudioChannel, ChannelState>,
+    duckings: Vec<Ducking>,
+    /// The only channel that is audible, if any
+    solo: Option<AudioChannel>,
+    /// The volume of the master track, applied by its gain effect
+    master_volume: f64,
+    queues: HashMap<AudioChannel, AudioQueue>,
+    /// The settings of the audio that was played last in each channel
+    last_played: HashMap<AudioChannel, PlayAudioSettings>,
+    tracks: HashMap<AudioMixerTrack, TrackHandle>,
+    track_volumes: HashMap<AudioMixerTrack, f64>,
+    /// The volume parameters of the gain effects on the mixer tracks
+    track_gains: HashMap<AudioMixerTrack, ParameterHandle>,
+    reverbs: HashMap<AudioMixerTrack, TrackReverb>,
+    /// Sidechain compressors by their compressed track
+    sidechains: HashMap<AudioMixerTrack, Sidechain>,
+    filters: HashMap<AudioMixerTrack, TrackFilter>,
+    pitch_shifts: HashMap<AudioMixerTrack, TrackPitchShift>,
+    /// The track that all other tracks play on, so effects can be applied to the whole output
+    ///
+    /// Kira offers no handle to add effects to its main track.
+    master_track: Option<TrackHandle>,
+    master_gain: Option<ParameterHandle>,
+    master_limiter: Option<MasterLimiter>,
+    master_meter: Option<TrackMeter>,
+    meters: HashMap<AudioMixerTrack, TrackMeter>,
+    asset_events: EventReader<AssetEvent<AudioSource>>,
+    restart_on_reload: bool,
+    /// Audio sources to add to Kira as soon as they are loaded
+    preloads: Vec<Handle<AudioSource>>,
+    /// Seconds that queued commands have been waiting for each audio source to load
+    load_waits: HashMap<Handle<AudioSource>, f64>,
+    /// Errors that are sent as events at the end of the frame
+    errors: Vec<AudioError>,
+    metronomes: HashMap<AudioChannel, ChannelMetronome>,
+    /// The audio backend, if it runs without an audio device
+    #[cfg(feature = "headless")]
+    headless_backend: Option<Backend>,
+    /// Frames that are not yet processed by the headless backend
+    #[cfg(feature = "headless")]
+    headless_frames: f64,
+    /// The settings to create the audio manager with
+    settings: AudioSettings,
+    /// Whether creating the audio manager was attempted
+    initialized: bool,
+    /// Whether more commands were queued than are run per frame, to warn only once
+    command_limit_exceeded: bool,
+    /// Whether the app exit waits for the audio to fade out
+    exit_fade: ExitFade,
+    exit_reader: EventReader<AppExit>,
+    /// The name of the chosen output device, `None` for the default one
+    #[cfg(feature = "output_device")]
+    output_device: Option<String>,
+    #[cfg(feature = "output_device")]
+    device_output: Option<DeviceOutput>,
+}
+
+/// Audio sources that are played one after another in a channel
+#[derive(Default)]
+struct AudioQueue {
+    audio_sources: Vec<Handle<AudioSource>>,
+    next: usize,
+    looped: bool,
+    /// The instance playing the current audio source
+    current: Option<u64>,
+}
+
+impl AudioQueue {
+    fn next_audio_source(&mut self) -> Option<Handle<AudioSource>> {
+        if self.next >= self.audio_sources.len() {
+            if !self.looped || self.audio_sources.is_empty() {
+                return None;
+            }
+            self.next = 0;
+        }
+        self.next += 1;
+        Some(self.audio_sources[self.next - 1].clone())
+    }
+}
+
+/// The commands that wait for earlier commands in the queue
+///
+/// Commands wait for earlier commands in their channel. Global commands can affect any
+/// channel, so they wait for all earlier commands and all later commands wait for them.
+#[derive(Default)]
+struct BlockedCommands {
+    channels: HashSet<AudioChannel>,
+    all: bool,
+}
+
+impl BlockedCommands {
+    fn is_blocked(&self, audio_command: &QueuedAudioCommand) -> bool {
+        match audio_command {
+            QueuedAudioCommand::Channel(_, channel_id) => {
+                self.all || self.channels.contains(channel_id)
+            }
+            QueuedAudioCommand::Global(GlobalAudioCommands::Batch(batch)) => batch
+                .iter()
+                .any(|audio_command| self.is_blocked(audio_command)),
+            QueuedAudioCommand::Global(_) => self.all || !self.channels.is_empty(),
+        }
+    }
+
+    fn block(&mut self, audio_command: &QueuedAudioCommand) {
+        match audio_command {
+            QueuedAudioCommand::Channel(_, channel_id) => {
+                self.channels.insert(channel_id.clone());
+            }
+            QueuedAudioCommand::Global(GlobalAudioCommands::Batch(batch)) => {
+                for audio_command in batch.iter() {
+                    self.block(audio_command);
+                }
+            }
+            QueuedAudioCommand::Global(_) => self.all = true,
+        }
+    }
+}
+
+/// Lowers the volume of one channel while audio is playing in another one
+struct Ducking {
+    ducked: AudioChannel,
+    trigger: AudioChannel,
+    settings: DuckingSettings,
+}
+
+/// A reverb effect on a mixer track, controlled by parameters
+struct TrackReverb {
+    settings: ReverbSettings,
+    effect: EffectHandle,
+    wet: ParameterHandle,
+    room_size: ParameterHandle,
+}
+
+/// A compressor on one mixer track that is controlled by the level of another one
+struct Sidechain {
+    key: AudioMixerTrack,
+    /// Kept to add the sidechain again after switching the output device
+    #[cfg_attr(not(feature = "output_device"), allow(dead_code))]
+    settings: SidechainSettings,
+    detector: EffectHandle,
+    compressor: EffectHandle,
+}
+
+/// The limiter on the master track, controlled by parameters
+struct MasterLimiter {
+    settings: LimiterSettings,
+    effect: EffectHandle,
+    threshold: ParameterHandle,
+    release: ParameterHandle,
+}
+
+/// A level meter on a mixer track
+///
+/// The meter has to be the last effect of the track to measure the audio after all other effects.
+struct TrackMeter {
+    effect: EffectHandle,
+    levels: Arc<MeterLevels>,
+}
+
+impl TrackMeter {
+    fn add(track_handle: &mut TrackHandle, levels: Arc<MeterLevels>) -> Option<Self> {
+        match track_handle.add_effect(Meter::new(levels.clone()), EffectSettings::new()) {
+            Ok(effect) => Some(TrackMeter { effect, levels }),
+            Err(error) => {
+                warn!("Failed to add a level meter: {:?}", error);
+                None
+            }
+        }
+    }
+
+    /// Add the meter again, so it comes after effects that were added after it
+    fn move_last(&mut self, track_handle: &mut TrackHandle) {
+        if let Err(error) = track_handle.remove_effect(&self.effect) {
+            warn!("Failed to remove a level meter: {:?}", error);
+            return;
+        }
+        if let Some(meter) = Self::add(track_handle, self.levels.clone()) {
+            *self = meter;
+        }
+    }
+}
+
+/// A filter effect on a mixer track, controlled by parameters
+struct TrackFilter {
+    settings: FilterSettings,
+    effect: EffectHandle,
+    cutoff: ParameterHandle,
+    resonance: ParameterHandle,
+}
+
+/// A pitch shifter on a mixer track, controlled by a parameter in semitones
+struct TrackPitchShift {
+    semitones_target: f64,
+    effect: EffectHandle,
+    semitones: ParameterHandle,
+}
+
+/// A playing instance together with the information needed to track its position
+///
+/// Kira does not report the playback position of a playing instance,
+/// so it is advanced every frame based on the elapsed time and the pitch.
+struct AudioInstance {
+    id: u64,
+    play_settings: PlayAudioSettings,
+    handle: InstanceHandle,
+    volume: f64,
+    /// The panning the instance was given itself, instead of following its channel
+    panning: Option<f64>,
+    /// The attenuation of the instance's emitter, multiplied with its volume
+    spatial_volume: f64,
+    spatial_panning: f64,
+    /// The attenuation and panning that the smoothed spatial values approach
+    spatial_target: Option<(f64, f64)>,
+    pitch_factor: f64,
+    position: f64,
+    duration: f64,
+    loop_start: Option<f64>,
+    reversed: bool,
+    stopped_by_command: bool,
+    paused_by_pause_all: bool,
+    on_finish: Option<u64>,
+}
+
+impl AudioInstance {
+    /// Whether the instance is stopped or fading out before it stops
+    fn is_stopping(&self) -> bool {
+        self.stopped_by_command
+            || matches!(
+                self.handle.state(),
+                InstanceState::Stopping | InstanceState::Stopped
+            )
+    }
+
+    fn update_position(&mut self, delta: f64, pitch: f64) {
+        match self.handle.state() {
+            InstanceState::Playing | InstanceState::Pausing(_) | InstanceState::Stopping => {
+                let mut step = delta * pitch * self.pitch_factor;
+                if self.reversed {
+                    step = -step;
+                }
+                self.position = self.wrap_position(self.position + step);
+            }
+            InstanceState::Paused(position) => self.position = position,
+            InstanceState::Stopped => (),
+        }
+    }
+
+    /// Keep a position within the instance
+    ///
+    /// Positions after the end wrap around to the loop start for looped instances
+    /// and are clamped to the end otherwise. Negative positions are clamped to the start.
+    /// Reversed looped instances wrap around from before the loop start to the end.
+    fn wrap_position(&self, position: f64) -> f64 {
+        if let (true, Some(loop_start)) = (self.reversed, self.loop_start) {
+            if position < loop_start && loop_start < self.duration {
+                return self.duration - (loop_start - position) % (self.duration - loop_start);
+            }
+        }
+        if position < 0.0 {
+            return 0.0;
+        }
+        if position < self.duration {
+            return position;
+        }
+        match self.loop_start {
+            Some(loop_start) if loop_start < self.duration => {
+                loop_start + (position - loop_start) % (self.duration - loop_start)
+            }
+            _ => self.duration,
+        }
+    }
+
+    fn seek_to(&mut self, position: f64) -> Result<(), InstanceHandleError> {
+        let position = self.wrap_position(position);
+        self.handle.seek_to(position)?;
+        self.position = position;
+        Ok(())
+    }
 }
 
 impl Default for AudioOutput {
     fn default() -> Self {
-        Self {
-            manager: AudioManager::new(AudioManagerSettings::default()).unwrap(),
+        AudioOutput::new(&AudioSettings::default())
+    }
+}
+
+impl AudioOutput {
+    pub(crate) fn new(settings: &AudioSettings) -> Self {
+        let mut audio_output = Self {
+            manager: None,
+            #[cfg(feature = "headless")]
+            headless_backend: None,
+            #[cfg(feature = "headless")]
+            headless_frames: 0.0,
             sounds: HashMap::default(),
+            seam_sounds: HashMap::default(),
             arrangements: HashMap::default(),
+            arrangement_last_played: HashMap::default(),
+            arrangement_plays: 0,
+            cooldowns: HashMap::default(),
             instances: HashMap::default(),
+            streams: HashMap::default(),
             channels: HashMap::default(),
+            duckings: Vec::default(),
+            solo: None,
+            master_volume: 1.0,
+            queues: HashMap::default(),
+            last_played: HashMap::default(),
+            tracks: HashMap::default(),
+            track_volumes: HashMap::default(),
+            track_gains: HashMap::default(),
+            reverbs: HashMap::default(),
+            sidechains: HashMap::default(),
+            filters: HashMap::default(),
+            pitch_shifts: HashMap::default(),
+            master_track: None,
+            master_gain: None,
+            master_limiter: None,
+            master_meter: None,
+            meters: HashMap::default(),
+            asset_events: EventReader::default(),
+            restart_on_reload: settings.restart_on_reload,
+            preloads: Vec::default(),
+            load_waits: HashMap::default(),
+            errors: Vec::default(),
+            metronomes: HashMap::default(),
+            settings: settings.clone(),
+            initialized: false,
+            command_limit_exceeded: false,
+            exit_fade: ExitFade::Waiting,
+            exit_reader: EventReader::default(),
+            #[cfg(feature = "output_device")]
+            output_device: None,
+            #[cfg(feature = "output_device")]
+            device_output: None,
+        };
+        if !settings.defer_initialization {
+            audio_output.initialize();
+        }
+        audio_output
+    }
+
+    /// Create the audio manager, unless that was already attempted
+    pub(crate) fn initialize(&mut self) {
+        if self.initialized {
+            return;
+        }
+        self.initialized = true;
+        let manager_settings = AudioManagerSettings {
+            num_sounds: self.settings.num_sounds,
+            num_arrangements: self.settings.num_arrangements,
+            num_instances: self.settings.num_instances,
+            ..Default::default()
+        };
+        #[cfg(feature = "headless")]
+        let mut manager = if self.settings.headless {
+            let (manager, backend) = AudioManager::new_without_audio_thread(manager_settings);
+            self.headless_backend = Some(backend);
+            Some(manager)
+        } else {
+            self.create_manager(manager_settings)
+        };
+        #[cfg(not(feature = "headless"))]
+        let mut manager = self.create_manager(manager_settings);
+        self.master_track = manager.as_mut().and_then(Self::create_master_track);
+        if let (Some(manager), Some(master_track)) = (manager.as_mut(), self.master_track.as_mut())
+        {
+            self.master_gain = Self::add_gain(manager, master_track, self.master_volume);
+        }
+        self.master_meter = self
+            .master_track
+            .as_mut()
+            .and_then(|master_track| TrackMeter::add(master_track, Arc::default()));
+        self.manager = manager;
+        if let Some(limiter) = self.settings.master_limiter {
+            self.set_master_limiter(limiter);
+        }
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn create_master_track(manager: &mut AudioManager) -> Option<TrackHandle> {
+        match manager.add_sub_track(TrackSettings::new()) {
+            Ok(track) => Some(track),
+            Err(error) => {
+                warn!("Failed to create the master track: {:?}", error);
+                None
+            }
+        }
+    }
+
+    /// Add the effect that applies the volume of a track, before any other effect of the track
+    fn add_gain(
+        manager: &mut AudioManager,
+        track_handle: &mut TrackHandle,
+        volume: f64,
+    ) -> Option<ParameterHandle> {
+        let parameter = match manager.add_parameter(ParameterSettings::new().value(volume)) {
+            Ok(parameter) => parameter,
+            Err(error) => {
+                warn!("Failed to create a track volume parameter: {:?}", error);
+                return None;
+            }
+        };
+        match track_handle.add_effect(Gain::new((&parameter).into()), EffectSettings::new()) {
+            Ok(_) => Some(parameter),
+            Err(error) => {
+                warn!("Failed to add the volume to a track: {:?}", error);
+                None
+            }
+        }
+    }
+
+    /// The Kira track that plays all audio without a mixer track
+    fn master_track_index(&self) -> TrackIndex {
+        self.master_track
+            .as_ref()
+            .map_or(TrackIndex::Main, |track| track.index())
+    }
+
+    #[cfg(not(feature = "output_device"))]
+    fn create_manager(&mut self, manager_settings: AudioManagerSettings) -> Option<AudioManager> {
+        match AudioManager::new(manager_settings) {
+            Ok(manager) => Some(manager),
+            Err(error) => {
+                warn!(
+                    "Failed to initialize the audio manager, audio is disabled: {:?}",
+                    error
+                );
+                None
+            }
+        }
+    }
+
+    /// Create the audio manager on the chosen output device
+    ///
+    /// Falls back to the default output device if the chosen one can not be used.
+    #[cfg(feature = "output_device")]
+    fn create_manager(&mut self, manager_settings: AudioManagerSettings) -> Option<AudioManager> {
+        if self.output_device.is_some() {
+            match DeviceOutput::create(self.output_device.as_deref(), manager_settings.clone()) {
+                Ok((manager, device_output)) => {
+                    self.device_output = Some(device_output);
+                    return Some(manager);
+                }
+                Err(message) => {
+                    let device = self.output_device.take();
+                    self.report(AudioError::OutputDevice { device, message });
+                }
+            }
+        }
+        match DeviceOutput::create(None, manager_settings) {
+            Ok((manager, device_output)) => {
+                self.device_output = Some(device_output);
+                Some(manager)
+            }
+            Err(message) => {
+                warn!(
+                    "Failed to initialize the audio manager, audio is disabled: {}",
+                    message
+                );
+                None
+            }
+        }
+    }
+
+    /// Create a new audio manager on the given output device
+    ///
+    /// Everything that was added to the previous audio manager is gone, so playing
+    /// audio stops. The settings of the channels are kept and the effects of the mixer
+    /// tracks, the master limiter and the metronomes are created again with their last
+    /// settings. Metronomes start again from the first beat.
+    #[cfg(feature = "output_device")]
+    fn set_output_device(&mut self, device: Option<String>) {
+        #[cfg(feature = "headless")]
+        if self.headless_backend.is_some() {
+            return;
+        }
+        self.output_device = device;
+        let limiter = self
+            .master_limiter
+            .take()
+            .map(|master_limiter| master_limiter.settings);
+        let reverbs: Vec<(AudioMixerTrack, ReverbSettings)> = self
+            .reverbs
+            .drain()
+            .map(|(track, reverb)| (track, reverb.settings))
+            .collect();
+        let filters: Vec<(AudioMixerTrack, FilterSettings)> = self
+            .filters
+            .drain()
+            .map(|(track, filter)| (track, filter.settings))
+            .collect();
+        let pitch_shifts: Vec<(AudioMixerTrack, f64)> = self
+            .pitch_shifts
+            .drain()
+            .map(|(track, pitch_shift)| (track, pitch_shift.semitones_target))
+            .collect();
+        let sidechains: Vec<(AudioMixerTrack, Sidechain)> = self.sidechains.drain().collect();
+        let metronomes: Vec<(AudioChannel, f64, Vec<f64>)> = self
+            .metronomes
+            .drain()
+            .map(|(channel, metronome)| {
+                let intervals = metronome
+                    .intervals
+                    .iter()
+                    .map(|(interval, _)| *interval)
+                    .collect();
+                (channel, metronome.tempo, intervals)
+            })
+            .collect();
+        self.sounds.clear();
+        self.seam_sounds.clear();
+        self.arrangements.clear();
+        self.arrangement_last_played.clear();
+        self.cooldowns.clear();
+        self.instances.clear();
+        self.streams.clear();
+        self.queues.clear();
+        self.tracks.clear();
+        self.track_gains.clear();
+        self.meters.clear();
+        self.master_track = None;
+        self.master_gain = None;
+        self.master_meter = None;
+        for channel_state in self.channels.values_mut() {
+            channel_state.volume_parameter = None;
+            channel_state.pitch_parameter = None;
+            channel_state.panning_parameter = None;
+        }
+        // Dropping the old manager before its stream stops its audio
+        self.manager = None;
+        self.device_output = None;
+        self.initialized = false;
+        self.initialize();
+        if let Some(limiter) = limiter {
+            self.set_master_limiter(limiter);
+        }
+        let tracks: HashSet<AudioMixerTrack> = self
+            .channels
+            .values()
+            .filter_map(|channel_state| channel_state.track.clone())
+            .collect();
+        for track in tracks.iter() {
+            self.get_or_create_track(track);
+        }
+        for (track, settings) in filters {
+            self.set_filter(track, settings, None);
+        }
+        for (track, semitones) in pitch_shifts {
+            self.set_pitch_shift(track, semitones, None);
+        }
+        for (track, settings) in reverbs {
+            self.set_reverb(track, settings);
+        }
+        for (compressed, sidechain) in sidechains {
+            self.add_sidechain(compressed, sidechain.key, sidechain.settings);
+        }
+        for (channel, tempo, intervals) in metronomes {
+            self.start_metronome(channel, tempo, &intervals);
+        }
+    }
+
+    /// Switch to the default output device if the current one stopped working
+    #[cfg(feature = "output_device")]
+    pub(crate) fn check_output_device(&mut self) {
+        let lost = match self.device_output.as_ref() {
+            Some(device_output) => device_output.is_lost(),
+            None => false,
+        };
+        if !lost {
+            return;
+        }
+        self.report(AudioError::OutputDevice {
+            device: self.output_device.clone(),
+            message: "The device stopped working".to_owned(),
+        });
+        self.set_output_device(None);
+    }
+
+    /// Process the audio of the elapsed time when running without an audio device
+    #[cfg(feature = "headless")]
+    fn process_headless(&mut self, delta: Option<f64>) {
+        let frames = match (self.settings.headless_frames_per_update, delta) {
+            (Some(frames), _) => frames as usize,
+            (None, Some(delta)) => {
+                self.headless_frames += delta * HEADLESS_SAMPLE_RATE;
+                let frames = self.headless_frames.floor();
+                self.headless_frames -= frames;
+                frames as usize
+            }
+            (None, None) => return,
+        };
+        if let Some(backend) = self.headless_backend.as_mut() {
+            for _ in 0..frames {
+                backend.process();
+            }
+        }
+    }
+
+    /// The seconds that the audio advances in this frame, given the time of the bevy frame
+    ///
+    /// With a fixed number of headless frames per update, the audio clock follows the
+    /// processed audio instead of the time.
+    fn audio_delta(&self, delta: Option<f64>) -> Option<f64> {
+        #[cfg(feature = "headless")]
+        if let (true, Some(frames)) = (
+            self.headless_backend.is_some(),
+            self.settings.headless_frames_per_update,
+        ) {
+            return Some(frames as f64 / HEADLESS_SAMPLE_RATE);
+        }
+        delta
+    }
+
+    /// Log the error and keep it to be sent as an event
+    fn report(&mut self, error: AudioError) {
+        error!("{}", error);
+        self.errors.push(error);
+    }
+
+    pub(crate) fn is_available(&self) -> bool {
+        self.manager.is_some()
+    }
+
+    fn get_or_create_sound(
+        &mut self,
+        audio_source: &AudioSource,
+        audio_source_handle: Handle<AudioSource>,
+    ) -> Option<SoundHandle> {
+        if let Some(handle) = self.sounds.get(&audio_source_handle) {
+            return Some(handle.clone());
+        }
+
+        let sound = audio_source.sound.clone();
+        let handle = match self.manager.as_mut()?.add_sound(sound) {
+            Ok(handle) => handle,
+            Err(error) => {
+                self.report(AudioError::AddSound {
+                    audio_source: audio_source_handle,
+                    message: format!("{:?}", error),
+                });
+                return None;
+            }
+        };
+        self.sounds.insert(audio_source_handle, handle.clone());
+        Some(handle)
+    }
+
+    fn play_arrangement(
+        &mut self,
+        mut arrangement_handle: ArrangementHandle,
+        play_settings: &PlayAudioSettings,
+        channel: &AudioChannel,
+        instance_settings: &PlayInstanceSettings,
+    ) {
+        let mut settings = InstanceSettings::new()
+            .volume(self.instance_volume(channel, instance_settings.volume))
+            .start_position(instance_settings.start_position)
+            .track(self.channel_track_index(channel));
+        if play_settings.reversed {
+            // Kira starts reversed instances at the start position counted from the end
+            settings = settings.reverse();
+        }
+        if let Some((duration, curve)) = instance_settings.crossfade {
+            settings = settings.fade_in_tween(curve.tween(duration, true));
+        } else if let Some(fade_in) = instance_settings.fade_in {
+            settings = settings.fade_in_tween(fade_in);
+        }
+        let has_room = if instance_settings.crossfade.is_some() {
+            // Everything in the channel fades out, so only a channel without voices rejects it
+            !matches!(
+                self.channels
+                    .get(channel)
+                    .and_then(|channel_state| channel_state.max_voices),
+                Some((0, _))
+            )
+        } else {
+            self.make_room_for_voice(channel)
+        };
+        if !has_room {
+            return;
+        }
+        settings = settings.pitch(self.instance_pitch(channel, instance_settings.pitch_factor));
+        settings = settings.panning(match instance_settings.panning {
+            Some(panning) => Value::Fixed(panning),
+            None => self.channel_panning(channel),
+        });
+        let mut instance_handle = match arrangement_handle.play(settings) {
+            Ok(instance_handle) => instance_handle,
+            Err(error) => {
+                self.report(AudioError::Play {
+                    channel: channel.clone(),
+                    message: format!("{:?}", error),
+                });
+                return;
+            }
+        };
+        if let Some((duration, curve)) = instance_settings.crossfade {
+            // Only fade out the channel once the new instance plays. It joins the
+            // channel afterwards, so it is not faded out itself.
+            self.stop_with_fade(channel.clone(), curve.tween(duration, false));
+        }
+        let quantize = instance_settings
+            .quantize
+            .filter(|_| self.metronomes.contains_key(channel));
+        if instance_settings.held || quantize.is_some() {
+            // Paused before the first frame is played, until the instance is released
+            if let Err(error) = instance_handle.pause(PauseInstanceSettings::new()) {
+                warn!(
+                    "Failed to hold back an instance in channel {:?}: {:?}",
+                    channel, error
+                );
+            }
+        }
+        let mut instance = AudioInstance {
+            id: instance_settings.id,
+            play_settings: play_settings.clone(),
+            handle: instance_handle,
+            volume: instance_settings.volume,
+            panning: instance_settings.panning,
+            spatial_volume: 1.0,
+            spatial_panning: 0.5,
+            spatial_target: None,
+            pitch_factor: instance_settings.pitch_factor,
+            position: 0.0,
+            duration: arrangement_handle.duration(),
+            loop_start: arrangement_handle.default_loop_start(),
+            reversed: play_settings.reversed,
+            stopped_by_command: false,
+            paused_by_pause_all: false,
+            on_finish: instance_settings.on_finish,
+        };
+        instance.position = if instance.reversed {
+            instance.wrap_position(instance.duration - instance_settings.start_position)
+        } else {
+            instance.wrap_position(instance_settings.start_position)
+        };
+        self.last_played
+            .insert(channel.clone(), play_settings.clone());
+        if let Some(instances) = self.instances.get_mut(channel) {
+            instances.push(instance);
+        } else {
+            self.instances.insert(channel.clone(), vec![instance]);
+        }
+        if quantize.is_some() {
+            self.release_held_instances(channel, &[instance_settings.id], quantize);
+        }
+    }
+
+    /// Start held instances of the channel together, optionally on the next metronome interval
+    ///
+    /// The instances were paused before they played their first frame. A Kira sequence
+    /// resumes them on the audio thread, so they start on exactly the same frame.
+    fn release_held_instances(
+        &mut self,
+        channel: &AudioChannel,
+        ids: &[u64],
+        interval: Option<f64>,
+    ) {
+        let mut handles: Vec<InstanceHandle> = match self.instances.get(channel) {
+            Some(instances) => instances
+                .iter()
+                .filter(|instance| ids.contains(&instance.id))
+                .map(|instance| instance.handle.clone())
+                .collect(),
+            None => return,
+        };
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let mut sequence = Sequence::<()>::new(SequenceSettings::new());
+        let mut sequence_settings = SequenceInstanceSettings::new();
+        if let Some(interval) = interval {
+            if let Some(metronome) = self.metronomes.get(channel) {
+                sequence.wait_for_interval(interval);
+                sequence_settings = sequence_settings.metronome(metronome.handle.id());
+            }
+        }
+        for handle in handles.iter() {
+            sequence.resume_instance(handle.id(), ResumeInstanceSettings::new());
+        }
+        if let Err(error) = manager.start_sequence(sequence, sequence_settings) {
+            warn!(
+                "Failed to schedule the start of instances in channel {:?}, playing them now: {:?}",
+                channel, error
+            );
+            for handle in handles.iter_mut() {
+                if let Err(error) = handle.resume(ResumeInstanceSettings::new()) {
+                    warn!(
+                        "Failed to resume an instance in channel {:?}: {:?}",
+                        channel, error
+                    );
+                }
+            }
+        }
+    }
+
+    /// Stop instances until a new one fits into the maximum number of voices of the channel
+    ///
+    /// Returns false if the new instance should not be played.
+    fn make_room_for_voice(&mut self, channel: &AudioChannel) -> bool {
+        let (max_voices, stealing) = match self.channels.get(channel).and_then(|c| c.max_voices) {
+            Some(max_voices) => max_voices,
+            None => return true,
+        };
+        if max_voices == 0 {
+            return false;
+        }
+        let instances = match self.instances.get(channel) {
+            Some(instances) => instances,
+            None => return true,
+        };
+        let mut voices: Vec<&AudioInstance> = instances
+            .iter()
+            .filter(|instance| !instance.is_stopping())
+            .collect();
+        if voices.len() < max_voices {
+            return true;
+        }
+        if stealing == VoiceStealing::DropNew {
+            return false;
+        }
+        if stealing == VoiceStealing::StealQuietest {
+            // The sort is stable, so equally loud instances stay ordered from oldest to newest
+            voices.sort_by(|a, b| {
+                (a.volume * a.spatial_volume)
+                    .partial_cmp(&(b.volume * b.spatial_volume))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        let stolen: Vec<u64> = voices
+            .iter()
+            .take(voices.len() + 1 - max_voices)
+            .map(|instance| instance.id)
+            .collect();
+        for instance_id in stolen {
+            self.stop_instance(channel.clone(), instance_id);
+        }
+        true
+    }
+
+    /// Get the Kira track index of the given mixer track, creating a sub-track if needed
+    fn get_or_create_track(&mut self, track: &AudioMixerTrack) -> Option<&mut TrackHandle> {
+        if self.tracks.contains_key(track) {
+            return self.tracks.get_mut(track);
+        }
+        let parent_track = self.master_track_index();
+        let volume = self.track_volumes.get(track).copied().unwrap_or(1.0);
+        let manager = self.manager.as_mut()?;
+        match manager.add_sub_track(TrackSettings::new().parent_track(parent_track)) {
+            Ok(mut handle) => {
+                if let Some(gain) = Self::add_gain(manager, &mut handle, volume) {
+                    self.track_gains.insert(track.clone(), gain);
+                }
+                if let Some(meter) = TrackMeter::add(&mut handle, Arc::default()) {
+                    self.meters.insert(track.clone(), meter);
+                }
+                Some(self.tracks.entry(track.clone()).or_insert(handle))
+            }
+            Err(error) => {
+                warn!("Failed to create mixer track {:?}: {:?}", track, error);
+                None
+            }
+        }
+    }
+
+    /// The Kira track that audio in the given channel plays on
+    fn channel_track_index(&self, channel: &AudioChannel) -> TrackIndex {
+        self.channels
+            .get(channel)
+            .and_then(|channel_state| channel_state.track.as_ref())
+            .and_then(|track| self.tracks.get(track))
+            .map_or(self.master_track_index(), |handle| handle.index())
+    }
+
+    fn set_channel_track(&mut self, channel_id: AudioChannel, track: AudioMixerTrack) {
+        if self.get_or_create_track(&track).is_none() {
+            return;
+        }
+        self.channels.entry(channel_id).or_default().track = Some(track);
+    }
+
+    /// Change the volume of the given mixer track
+    ///
+    /// Kira cannot change the volume of a mixer track after creating it,
+    /// so the track volume is applied by a gain effect on the track.
+    fn set_track_volume(&mut self, track: AudioMixerTrack, volume: f64) {
+        if let Some(gain) = self.track_gains.get_mut(&track) {
+            if let Err(error) = gain.set(volume, instant_or(None)) {
+                warn!("Failed to set volume of track {:?}: {:?}", track, error);
+            }
+        }
+        self.track_volumes.insert(track, volume);
+    }
+
+    fn set_reverb(&mut self, track: AudioMixerTrack, settings: ReverbSettings) {
+        if let Some(reverb) = self.reverbs.get_mut(&track) {
+            reverb.settings = settings;
+            let wet = reverb.wet.set(settings.wet as f64, instant_or(None));
+            let room_size = reverb
+                .room_size
+                .set(settings.room_size as f64, instant_or(None));
+            if let Err(error) = wet.and(room_size) {
+                warn!("Failed to change reverb of track {:?}: {:?}", track, error);
+            }
+            return;
+        }
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let wet = manager.add_parameter(ParameterSettings::new().value(settings.wet as f64));
+        let room_size =
+            manager.add_parameter(ParameterSettings::new().value(settings.room_size as f64));
+        let (wet, room_size) = match (wet, room_size) {
+            (Ok(wet), Ok(room_size)) => (wet, room_size),
+            (wet, room_size) => {
+                warn!(
+                    "Failed to create reverb parameters for track {:?}: {:?}",
+                    track,
+                    wet.and(room_size).err()
+                );
+                return;
+            }
+        };
+        let reverb = Reverb::new((&wet).into(), (&room_size).into());
+        let track_handle = match self.get_or_create_track(&track) {
+            Some(track_handle) => track_handle,
+            None => return,
+        };
+        match track_handle.add_effect(reverb, EffectSettings::new()) {
+            Ok(effect) => {
+                self.keep_meter_last(Some(&track));
+                self.reverbs.insert(
+                    track,
+                    TrackReverb {
+                        settings,
+                        effect,
+                        wet,
+                        room_size,
+                    },
+                );
+            }
+            Err(error) => warn!("Failed to add reverb to track {:?}: {:?}", track, error),
+        }
+    }
+
+    fn set_master_limiter(&mut self, settings: LimiterSettings) {
+        let threshold = settings.threshold as f64;
+        let release = settings.release.as_secs_f64();
+        if let Some(limiter) = self.master_limiter.as_mut() {
+            limiter.settings = settings;
+            let threshold = limiter.threshold.set(threshold, instant_or(None));
+            let release = limiter.release.set(release, instant_or(None));
+            if let Err(error) = threshold.and(release) {
+                warn!("Failed to change the master limiter: {:?}", error);
+            }
+            return;
+        }
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let master_track = match self.master_track.as_mut() {
+            Some(master_track) => master_track,
+            None => return,
+        };
+        let threshold = manager.add_parameter(ParameterSettings::new().value(threshold));
+        let release = manager.add_parameter(ParameterSettings::new().value(release));
+        let (threshold, release) = match (threshold, release) {
+            (Ok(threshold), Ok(release)) => (threshold, release),
+            (threshold, release) => {
+                warn!(
+                    "Failed to create the master limiter parameters: {:?}",
+                    threshold.and(release).err()
+                );
+                return;
+            }
+        };
+        let limiter = Limiter::new((&threshold).into(), (&release).into());
+        match master_track.add_effect(limiter, EffectSettings::new()) {
+            Ok(effect) => {
+                self.keep_meter_last(None);
+                self.master_limiter = Some(MasterLimiter {
+                    settings,
+                    effect,
+                    threshold,
+                    release,
+                });
+            }
+            Err(error) => warn!("Failed to add the master limiter: {:?}", error),
+        }
+    }
+
+    /// Move the level meter of a mixer track, or of the master track, behind new effects
+    fn keep_meter_last(&mut self, track: Option<&AudioMixerTrack>) {
+        let (track_handle, meter) = match track {
+            Some(track) => (self.tracks.get_mut(track), self.meters.get_mut(track)),
+            None => (self.master_track.as_mut(), self.master_meter.as_mut()),
+        };
+        if let (Some(track_handle), Some(meter)) = (track_handle, meter) {
+            meter.move_last(track_handle);
+        }
+    }
+
+    fn remove_master_limiter(&mut self) {
+        let limiter = match self.master_limiter.take() {
+            Some(limiter) => limiter,
+            None => return,
+        };
+        if let Some(master_track) = self.master_track.as_mut() {
+            if let Err(error) = master_track.remove_effect(&limiter.effect) {
+                warn!("Failed to remove the master limiter: {:?}", error);
+            }
+        }
+        if let Some(manager) = self.manager.as_mut() {
+            for parameter in [limiter.threshold, limiter.release].iter() {
+                if let Err(error) = manager.remove_parameter(parameter) {
+                    warn!("Failed to remove a master limiter parameter: {:?}", error);
+                }
+            }
+        }
+    }
+
+    fn add_sidechain(
+        &mut self,
+        compressed: AudioMixerTrack,
+        key: AudioMixerTrack,
+        settings: SidechainSettings,
+    ) {
+        self.remove_sidechain(&compressed);
+        if self.get_or_create_track(&compressed).is_none() {
+            return;
+        }
+        let key_track = match self.get_or_create_track(&key) {
+            Some(key_track) => key_track,
+            None => return,
+        };
+        let level = Arc::new(AtomicU32::new(0.0f32.to_bits()));
+        let detector = match key_track
+            .add_effect(SidechainDetector::new(level.clone()), EffectSettings::new())
+        {
+            Ok(detector) => detector,
+            Err(error) => {
+                warn!(
+                    "Failed to add the sidechain detector to track {:?}: {:?}",
+                    key, error
+                );
+                return;
+            }
+        };
+        let compressor = SidechainCompressor::new(level, settings);
+        let added = match self.tracks.get_mut(&compressed) {
+            Some(compressed_track) => {
+                compressed_track.add_effect(compressor, EffectSettings::new())
+            }
+            None => return,
+        };
+        match added {
+            Ok(compressor) => {
+                self.keep_meter_last(Some(&compressed));
+                self.sidechains.insert(
+                    compressed,
+                    Sidechain {
+                        key,
+                        settings,
+                        detector,
+                        compressor,
+                    },
+                );
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to add the sidechain compressor to track {:?}: {:?}",
+                    compressed, error
+                );
+                if let Some(Err(error)) = self
+                    .tracks
+                    .get_mut(&key)
+                    .map(|key_track| key_track.remove_effect(&detector))
+                {
+                    warn!(
+                        "Failed to remove the sidechain detector from track {:?}: {:?}",
+                        key, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn remove_sidechain(&mut self, compressed: &AudioMixerTrack) {
+        let sidechain = match self.sidechains.remove(compressed) {
+            Some(sidechain) => sidechain,
+            None => return,
+        };
+        if let Some(track_handle) = self.tracks.get_mut(compressed) {
+            if let Err(error) = track_handle.remove_effect(&sidechain.compressor) {
+                warn!(
+                    "Failed to remove the sidechain compressor from track {:?}: {:?}",
+                    compressed, error
+                );
+            }
+        }
+        if let Some(track_handle) = self.tracks.get_mut(&sidechain.key) {
+            if let Err(error) = track_handle.remove_effect(&sidechain.detector) {
+                warn!(
+                    "Failed to remove the sidechain detector from track {:?}: {:?}",
+                    sidechain.key, error
+                );
+            }
+        }
+    }
+
+    fn remove_reverb(&mut self, track: &AudioMixerTrack) {
+        let reverb = match self.reverbs.remove(track) {
+            Some(reverb) => reverb,
+            None => return,
+        };
+        if let Some(track_handle) = self.tracks.get_mut(track) {
+            if let Err(error) = track_handle.remove_effect(&reverb.effect) {
+                warn!(
+                    "Failed to remove reverb from track {:?}: {:?}",
+                    track, error
+                );
+            }
+        }
+        if let Some(manager) = self.manager.as_mut() {
+            for parameter in [reverb.wet, reverb.room_size].iter() {
+                if let Err(error) = manager.remove_parameter(parameter) {
+                    warn!(
+                        "Failed to remove reverb parameter of track {:?}: {:?}",
+                        track, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn set_filter(
+        &mut self,
+        track: AudioMixerTrack,
+        settings: FilterSettings,
+        tween: Option<Tween>,
+    ) {
+        match self.filters.get_mut(&track) {
+            Some(filter) if filter.settings.mode == settings.mode => {
+                filter.settings = settings;
+                let cutoff = filter.cutoff.set(settings.cutoff, instant_or(tween));
+                let resonance = filter.resonance.set(settings.resonance, instant_or(tween));
+                if let Err(error) = cutoff.and(resonance) {
+                    warn!("Failed to change filter of track {:?}: {:?}", track, error);
+                }
+                return;
+            }
+            // The mode of a Kira filter cannot be changed, so it is replaced
+            Some(_) => self.remove_filter(&track),
+            None => (),
+        }
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let (initial_cutoff, initial_resonance) = match tween {
+            Some(_) => (settings.mode.open_cutoff(), 0.0),
+            None => (settings.cutoff, settings.resonance),
+        };
+        let cutoff = manager.add_parameter(ParameterSettings::new().value(initial_cutoff));
+        let resonance = manager.add_parameter(ParameterSettings::new().value(initial_resonance));
+        let (mut cutoff, mut resonance) = match (cutoff, resonance) {
+            (Ok(cutoff), Ok(resonance)) => (cutoff, resonance),
+            (cutoff, resonance) => {
+                warn!(
+                    "Failed to create filter parameters for track {:?}: {:?}",
+                    track,
+                    cutoff.and(resonance).err()
+                );
+                return;
+            }
+        };
+        let filter = Filter::new(
+            kira::mixer::effect::filter::FilterSettings::new()
+                .mode(settings.mode.into())
+                .cutoff(&cutoff)
+                .resonance(&resonance),
+        );
+        if let Some(tween) = tween {
+            let cutoff_tween = cutoff.set(settings.cutoff, tween);
+            let resonance_tween = resonance.set(settings.resonance, tween);
+            if let Err(error) = cutoff_tween.and(resonance_tween) {
+                warn!("Failed to tween filter of track {:?}: {:?}", track, error);
+            }
+        }
+        let track_handle = match self.get_or_create_track(&track) {
+            Some(track_handle) => track_handle,
+            None => return,
+        };
+        match track_handle.add_effect(filter, EffectSettings::new()) {
+            Ok(effect) => {
+                self.keep_meter_last(Some(&track));
+                self.filters.insert(
+                    track,
+                    TrackFilter {
+                        settings,
+                        effect,
+                        cutoff,
+                        resonance,
+                    },
+                );
+            }
+            Err(error) => warn!("Failed to add filter to track {:?}: {:?}", track, error),
+        }
+    }
+
+    fn remove_filter(&mut self, track: &AudioMixerTrack) {
+        let filter = match self.filters.remove(track) {
+            Some(filter) => filter,
+            None => return,
+        };
+        if let Some(track_handle) = self.tracks.get_mut(track) {
+            if let Err(error) = track_handle.remove_effect(&filter.effect) {
+                warn!(
+                    "Failed to remove filter from track {:?}: {:?}",
+                    track, error
+                );
+            }
+        }
+        if let Some(manager) = self.manager.as_mut() {
+            for parameter in [filter.cutoff, filter.resonance].iter() {
+                if let Err(error) = manager.remove_parameter(parameter) {
+                    warn!(
+                        "Failed to remove filter parameter of track {:?}: {:?}",
+                        track, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn set_pitch_shift(&mut self, track: AudioMixerTrack, semitones: f64, tween: Option<Tween>) {
+        if let Some(pitch_shift) = self.pitch_shifts.get_mut(&track) {
+            pitch_shift.semitones_target = semitones;
+            if let Err(error) = pitch_shift.semitones.set(semitones, instant_or(tween)) {
+                warn!(
+                    "Failed to change pitch shift of track {:?}: {:?}",
+                    track, error
+                );
+            }
+            return;
+        }
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let initial_semitones = if tween.is_some() { 0.0 } else { semitones };
+        let mut parameter =
+            match manager.add_parameter(ParameterSettings::new().value(initial_semitones)) {
+                Ok(parameter) => parameter,
+                Err(error) => {
+                    warn!(
+                        "Failed to create pitch shift parameter for track {:?}: {:?}",
+                        track, error
+                    );
+                    return;
+                }
+            };
+        if let Some(tween) = tween {
+            if let Err(error) = parameter.set(semitones, tween) {
+                warn!(
+                    "Failed to tween pitch shift of track {:?}: {:?}",
+                    track, error
+                );
+            }
+        }
+        let pitch_shifter = PitchShifter::new((&parameter).into());
+        let track_handle = match self.get_or_create_track(&track) {
+            Some(track_handle) => track_handle,
+            None => return,
+        };
+        match track_handle.add_effect(pitch_shifter, EffectSettings::new()) {
+            Ok(effect) => {
+                self.keep_meter_last(Some(&track));
+                self.pitch_shifts.insert(
+                    track,
+                    TrackPitchShift {
+                        semitones_target: semitones,
+                        effect,
+                        semitones: parameter,
+                    },
+                );
+            }
+            Err(error) => warn!(
+                "Failed to add pitch shift to track {:?}: {:?}",
+                track, error
+            ),
+        }
+    }
+
+    fn remove_pitch_shift(&mut self, track: &AudioMixerTrack) {
+        let pitch_shift = match self.pitch_shifts.remove(track) {
+            Some(pitch_shift) => pitch_shift,
+            None => return,
+        };
+        if let Some(track_handle) = self.tracks.get_mut(track) {
+            if let Err(error) = track_handle.remove_effect(&pitch_shift.effect) {
+                warn!(
+                    "Failed to remove pitch shift from track {:?}: {:?}",
+                    track, error
+                );
+            }
+        }
+        if let Some(manager) = self.manager.as_mut() {
+            if let Err(error) = manager.remove_parameter(&pitch_shift.semitones) {
+                warn!(
+                    "Failed to remove pitch shift parameter of track {:?}: {:?}",
+                    track, error
+                );
+            }
+        }
+    }
+
+    /// Get the volume parameter of the given channel, creating it if needed
+    ///
+    /// All instances in a channel follow this parameter, so that changing and
+    /// tweening the channel volume affects all of them at once.
+    fn channel_volume_parameter(&mut self, channel: &AudioChannel) -> Option<&mut ParameterHandle> {
+        let volume = self.channel_volume(channel);
+        let manager = self.manager.as_mut()?;
+        let channel_state = self.channels.entry(channel.clone()).or_default();
+        if channel_state.volume_parameter.is_none() {
+            match manager.add_parameter(ParameterSettings::new().value(volume)) {
+                Ok(parameter) => channel_state.volume_parameter = Some(parameter),
+                Err(error) => {
+                    warn!(
+                        "Failed to create volume parameter for channel {:?}: {:?}",
+                        channel, error
+                    );
+                }
+            }
+        }
+        channel_state.volume_parameter.as_mut()
+    }
+
+    /// The volume value for an instance in the given channel
+    ///
+    /// The channel volume is multiplied with the volume of the instance.
+    fn instance_volume(&mut self, channel: &AudioChannel, volume: f64) -> Value<f64> {
+        if let Some(parameter) = self.channel_volume_parameter(channel) {
+            return Value::Parameter(
+                parameter.id(),
+                Mapping {
+                    input_range: (0.0, 1.0),
+                    output_range: (0.0, volume),
+                    ..Default::default()
+                },
+            );
+        }
+        Value::Fixed(self.channel_volume(channel) * volume)
+    }
+
+    /// Get the pitch parameter of a channel, creating it if needed
+    ///
+    /// Channels only get a pitch parameter once their pitch is tweened.
+    fn channel_pitch_parameter(&mut self, channel: &AudioChannel) -> Option<&mut ParameterHandle> {
+        let manager = self.manager.as_mut()?;
+        let channel_state = self.channels.entry(channel.clone()).or_default();
+        if channel_state.pitch_parameter.is_none() {
+            match manager.add_parameter(ParameterSettings::new().value(channel_state.pitch)) {
+                Ok(parameter) => channel_state.pitch_parameter = Some(parameter),
+                Err(error) => {
+                    warn!(
+                        "Failed to create pitch parameter for channel {:?}: {:?}",
+                        channel, error
+                    );
+                }
+            }
+        }
+        channel_state.pitch_parameter.as_mut()
+    }
+
+    /// Get the panning parameter of a channel, creating it if needed
+    ///
+    /// Channels only get a panning parameter once their panning is tweened.
+    fn channel_panning_parameter(
+        &mut self,
+        channel: &AudioChannel,
+    ) -> Option<&mut ParameterHandle> {
+        let manager = self.manager.as_mut()?;
+        let channel_state = self.channels.entry(channel.clone()).or_default();
+        if channel_state.panning_parameter.is_none() {
+            match manager.add_parameter(ParameterSettings::new().value(channel_state.panning)) {
+                Ok(parameter) => channel_state.panning_parameter = Some(parameter),
+                Err(error) => {
+                    warn!(
+                        "Failed to create panning parameter for channel {:?}: {:?}",
+                        channel, error
+                    );
+                }
+            }
+        }
+        channel_state.panning_parameter.as_mut()
+    }
+
+    /// The panning value for instances in the given channel
+    fn channel_panning(&self, channel: &AudioChannel) -> Value<f64> {
+        match self.channels.get(channel) {
+            Some(ChannelState {
+                panning_parameter: Some(parameter),
+                ..
+            }) => Value::from(parameter),
+            Some(channel_state) => Value::Fixed(channel_state.panning),
+            None => Value::Fixed(0.5),
+        }
+    }
+
+    /// The pitch value for an instance in the given channel
+    ///
+    /// The channel pitch is multiplied with the pitch factor of the instance.
+    fn instance_pitch(&self, channel: &AudioChannel, pitch_factor: f64) -> Value<f64> {
+        let channel_state = match self.channels.get(channel) {
+            Some(channel_state) => channel_state,
+            None => return Value::Fixed(pitch_factor),
+        };
+        match &channel_state.pitch_parameter {
+            Some(parameter) => Value::Parameter(
+                parameter.id(),
+                Mapping {
+                    input_range: (0.0, 1.0),
+                    output_range: (0.0, pitch_factor),
+                    ..Default::default()
+                },
+            ),
+            None => Value::Fixed(channel_state.pitch * pitch_factor),
+        }
+    }
+
+    /// Get the cached arrangement for the play settings, creating it if needed
+    ///
+    /// Arrangements are cached per channel, so that every channel
+    /// gets its own arrangement to play its instances from.
+    fn get_or_create_arrangement(
+        &mut self,
+        play_settings: &PlayAudioSettings,
+        audio_source: &AudioSource,
+        intro_source: Option<&AudioSource>,
+        channel: &AudioChannel,
+    ) -> Option<ArrangementHandle> {
+        let arrangement_key = (play_settings.arrangement_key(), channel.clone());
+        self.arrangement_plays += 1;
+        self.arrangement_last_played
+            .insert(arrangement_key.clone(), self.arrangement_plays);
+        if let Some(arrangement_handle) = self.arrangements.get(&arrangement_key) {
+            return Some(arrangement_handle.clone());
+        }
+        if let Some(max_cached_arrangements) = self.settings.max_cached_arrangements {
+            if self.arrangements.len() >= max_cached_arrangements {
+                self.evict_arrangement();
+            }
+        }
+        let sound_handle = self.get_or_create_sound(audio_source, play_settings.source.clone())?;
+        let intro_sound = match (intro_source, &play_settings.intro) {
+            (Some(intro_source), Some(intro)) => {
+                self.get_or_create_sound(intro_source, intro.clone())
+            }
+            _ => None,
+        };
+        let arrangement_handle = if let Some(intro_sound) = intro_sound {
+            self.create_intro_arrangement(&intro_sound, &sound_handle)
+        } else if let Some(loop_region) = &play_settings.loop_region {
+            self.create_region_arrangement(&sound_handle, loop_region)
+        } else if play_settings.looped {
+            self.create_looped_arrangement(&sound_handle, audio_source, &play_settings.source)
+        } else if play_settings.repeats > 1 {
+            self.create_repeated_arrangement(&sound_handle, play_settings.repeats)
+        } else {
+            self.create_arrangement(&sound_handle)
+        }?;
+        let arrangement_handle = match arrangement_handle {
+            Ok(arrangement_handle) => arrangement_handle,
+            Err(error) => {
+                self.report(AudioError::AddArrangement {
+                    audio_source: play_settings.source.clone(),
+                    message: format!("{:?}", error),
+                });
+                return None;
+            }
+        };
+        self.arrangements
+            .insert(arrangement_key, arrangement_handle.clone());
+        Some(arrangement_handle)
+    }
+
+    /// Remove the least recently played arrangement that no instance plays from anymore
+    fn evict_arrangement(&mut self) {
+        let instances = &self.instances;
+        let in_use = |(play_settings, channel): &(PlayAudioSettings, AudioChannel)| {
+            instances
+                .get(channel)
+                .into_iter()
+                .flatten()
+                .any(|instance| {
+                    &instance.play_settings == play_settings
+                        && instance.handle.state() != InstanceState::Stopped
+                })
+        };
+        let last_played = &self.arrangement_last_played;
+        let evicted = self
+            .arrangements
+            .keys()
+            .filter(|key| !in_use(key))
+            .min_by_key(|key| last_played.get(*key).copied().unwrap_or(0))
+            .cloned();
+        let evicted = match evicted {
+            Some(evicted) => evicted,
+            None => return,
+        };
+        self.arrangement_last_played.remove(&evicted);
+        let arrangement_handle = match self.arrangements.remove(&evicted) {
+            Some(arrangement_handle) => arrangement_handle,
+            None => return,
+        };
+        if let Some(manager) = self.manager.as_mut() {
+            if let Err(error) = manager.remove_arrangement(arrangement_handle.id()) {
+                warn!("Failed to remove arrangement: {:?}", error);
+            }
+        }
+    }
+
+    /// Stop all instances of the audio source and remove it from Kira
+    fn unload(&mut self, audio_source: &Handle<AudioSource>) {
+        let uses_source = |play_settings: &PlayAudioSettings| {
+            &play_settings.source == audio_source
+                || play_settings.intro.as_ref() == Some(audio_source)
+        };
+        for (channel, instances) in self.instances.iter_mut() {
+            for instance in instances.iter_mut() {
+                if !uses_source(&instance.play_settings) {
+                    continue;
+                }
+                instance.stopped_by_command = true;
+                if let Err(error) = instance.handle.stop(StopInstanceSettings::default()) {
+                    warn!(
+                        "Failed to stop instance in channel {:?}: {:?}",
+                        channel, error
+                    );
+                }
+            }
+        }
+        self.last_played
+            .retain(|_, play_settings| !uses_source(play_settings));
+        self.preloads.retain(|handle| handle != audio_source);
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        // Arrangements play the sound, so they are removed before it
+        let arrangements = &mut self.arrangements;
+        arrangements.retain(|(play_settings, _), arrangement_handle| {
+            if !uses_source(play_settings) {
+                return true;
+            }
+            if let Err(error) = manager.remove_arrangement(arrangement_handle.id()) {
+                warn!("Failed to remove arrangement: {:?}", error);
+            }
+            false
+        });
+        self.arrangement_last_played
+            .retain(|(play_settings, _), _| !uses_source(play_settings));
+        let sound_handles = self
+            .sounds
+            .remove(audio_source)
+            .into_iter()
+            .chain(self.seam_sounds.remove(audio_source));
+        for sound_handle in sound_handles {
+            if let Err(error) = manager.remove_sound(sound_handle.id()) {
+                warn!("Failed to remove sound: {:?}", error);
+            }
+        }
+    }
+
+    /// Unload audio sources that were removed or modified
+    ///
+    /// Kira keeps a copy of the audio data, so after a hot reload it would
+    /// continue playing the old data.
+    fn handle_asset_events(
+        &mut self,
+        asset_events: &Events<AssetEvent<AudioSource>>,
+        audio_sources: &Assets<AudioSource>,
+        audio: &Audio,
+    ) {
+        let events: Vec<(Handle<AudioSource>, bool)> = self
+            .asset_events
+            .iter(asset_events)
+            .filter_map(|event| match event {
+                AssetEvent::Removed { handle } => Some((handle.clone(), false)),
+                AssetEvent::Modified { handle } => Some((handle.clone(), true)),
+                AssetEvent::Created { .. } => None,
+            })
+            .collect();
+        for (audio_source, modified) in events.iter() {
+            let reloaded = if *modified && self.restart_on_reload {
+                self.reloaded_instances(audio_source)
+            } else {
+                vec![]
+            };
+            self.unload(audio_source);
+            // Restarted right away, so they play before any command queued after the reload
+            let mut commands = VecDeque::new();
+            for (command, channel) in reloaded {
+                self.run_command(&command, channel, audio_sources, audio, &mut commands);
+            }
+            audio.commands.write().extend(commands);
+        }
+    }
+
+    /// The commands playing the instances of a modified audio source again from their positions
+    ///
+    /// The restarted instances keep their ids, so existing instance handles stay valid.
+    /// Paused instances are held back, so they stay paused until they are resumed.
+    fn reloaded_instances(
+        &self,
+        audio_source: &Handle<AudioSource>,
+    ) -> Vec<(AudioCommands, AudioChannel)> {
+        let mut commands = vec![];
+        for (channel, instances) in self.instances.iter() {
+            let reloaded = instances.iter().filter(|instance| {
+                matches!(
+                    instance.handle.state(),
+                    InstanceState::Playing | InstanceState::Pausing(_) | InstanceState::Paused(_)
+                ) && !instance.stopped_by_command
+                    && (&instance.play_settings.source == audio_source
+                        || instance.play_settings.intro.as_ref() == Some(audio_source))
+            });
+            for instance in reloaded {
+                let instance_settings = PlayInstanceSettings {
+                    id: instance.id,
+                    pitch_factor: instance.pitch_factor,
+                    start_position: if instance.reversed {
+                        instance.duration - instance.position
+                    } else {
+                        instance.position
+                    },
+                    volume: instance.volume,
+                    panning: instance.panning,
+                    fade_in: None,
+                    crossfade: None,
+                    skip_if_playing: false,
+                    quantize: None,
+                    held: instance.handle.state() != InstanceState::Playing,
+                    on_finish: instance.on_finish,
+                };
+                commands.push((
+                    AudioCommands::Play(instance.play_settings.clone(), instance_settings),
+                    channel.clone(),
+                ));
+            }
+        }
+        commands
+    }
+
+    /// Whether an instance of the audio source is playing or paused in the channel
+    ///
+    /// Instances that fade out after a stop or a crossfade do not count.
+    fn is_playing_source(&self, channel: &AudioChannel, source: &Handle<AudioSource>) -> bool {
+        self.instances
+            .get(channel)
+            .into_iter()
+            .flatten()
+            .any(|instance| &instance.play_settings.source == source && !instance.is_stopping())
+    }
+
+    fn add_arrangement(
+        &mut self,
+        arrangement: Arrangement,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        Some(self.manager.as_mut()?.add_arrangement(arrangement))
+    }
+
+    fn create_arrangement(
+        &mut self,
+        sound_handle: &SoundHandle,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        let mut arrangement = Arrangement::new(ArrangementSettings::new().cooldown(0.0));
+        arrangement.add_clip(SoundClip::new(sound_handle, 0.0));
+        self.add_arrangement(arrangement)
+    }
+
+    /// Create an arrangement that plays the sound several times in a row
+    ///
+    /// Each repetition starts at the semantic duration of the sound, if it has one.
+    fn create_repeated_arrangement(
+        &mut self,
+        sound_handle: &SoundHandle,
+        repeats: u32,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        let length = sound_handle
+            .semantic_duration()
+            .unwrap_or_else(|| sound_handle.duration());
+        let mut arrangement = Arrangement::new(ArrangementSettings::new().cooldown(0.0));
+        for repeat in 0..repeats {
+            arrangement.add_clip(SoundClip::new(sound_handle, repeat as f64 * length));
+        }
+        self.add_arrangement(arrangement)
+    }
+
+    fn create_looped_arrangement(
+        &mut self,
+        sound_handle: &SoundHandle,
+        audio_source: &AudioSource,
+        audio_source_handle: &Handle<AudioSource>,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        if let Some(loop_start) = sound_handle.default_loop_start() {
+            let loop_end = sound_handle
+                .semantic_duration()
+                .unwrap_or_else(|| sound_handle.duration());
+            return self
+                .create_region_arrangement(sound_handle, &LoopRegion::new(loop_start, loop_end));
+        }
+        // Audio after the semantic duration rings out across the wrap, so
+        // only sources that loop as a whole need their seam corrected
+        if let (None, Some(loop_seam)) = (sound_handle.semantic_duration(), audio_source.loop_seam)
+        {
+            if let Some(seam_handle) =
+                self.get_or_create_seam_sound(&loop_seam, audio_source_handle)
+            {
+                return self
+                    .add_arrangement(loop_seam.loop_arrangement(sound_handle, &seam_handle));
+            }
+        }
+        let arrangement =
+            Arrangement::new_loop(sound_handle, LoopArrangementSettings::new().cooldown(0.0));
+        self.add_arrangement(arrangement)
+    }
+
+    fn get_or_create_seam_sound(
+        &mut self,
+        loop_seam: &LoopSeam,
+        audio_source_handle: &Handle<AudioSource>,
+    ) -> Option<SoundHandle> {
+        if let Some(handle) = self.seam_sounds.get(audio_source_handle) {
+            return Some(handle.clone());
+        }
+        match self.manager.as_mut()?.add_sound(loop_seam.sound()) {
+            Ok(handle) => {
+                self.seam_sounds
+                    .insert(audio_source_handle.clone(), handle.clone());
+                Some(handle)
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to add the loop seam of {:?}, it might click when looping: {:?}",
+                    audio_source_handle, error
+                );
+                None
+            }
+        }
+    }
+
+    fn create_intro_arrangement(
+        &mut self,
+        intro_sound_handle: &SoundHandle,
+        loop_sound_handle: &SoundHandle,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        // Both sounds are part of one arrangement, so the loop starts
+        // exactly at the end of the intro
+        let arrangement = Arrangement::new_loop_with_intro(
+            intro_sound_handle,
+            loop_sound_handle,
+            LoopArrangementSettings::new().cooldown(0.0),
+        );
+        self.add_arrangement(arrangement)
+    }
+
+    fn create_region_arrangement(
+        &mut self,
+        sound_handle: &SoundHandle,
+        loop_region: &LoopRegion,
+    ) -> Option<Result<ArrangementHandle, AddArrangementError>> {
+        let loop_end = loop_region.end().min(sound_handle.duration());
+        let mut loop_start = loop_region.start().max(0.0);
+        if loop_start >= loop_end {
+            warn!(
+                "Loop start {} is not before the loop end {}, looping from the start instead",
+                loop_start, loop_end
+            );
+            loop_start = 0.0;
+        }
+        // Kira jumps back to the loop start whenever an instance reaches
+        // the end of the arrangement, so the sound is cut at the loop end
+        let mut arrangement = Arrangement::new(
+            ArrangementSettings::new()
+                .cooldown(0.0)
+                .default_loop_start(loop_start),
+        );
+        arrangement.add_clip(SoundClip::new(sound_handle, 0.0).trim(loop_end));
+        self.add_arrangement(arrangement)
+    }
+
+    fn play_streamed(&mut self, path: &Path, looped: bool, channel: &AudioChannel) {
+        let volume = self.channel_volume(channel);
+        let stream = match FileStream::open(path, looped, volume) {
+            Ok(stream) => stream,
+            Err(error) => {
+                self.report(AudioError::Stream {
+                    channel: channel.clone(),
+                    path: path.to_path_buf(),
+                    message: format!("{:?}", error),
+                });
+                return;
+            }
+        };
+        let control = stream.control();
+        let track = self.channel_track_index(channel);
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        match manager.add_stream(stream, track) {
+            Ok(id) => self
+                .streams
+                .entry(channel.clone())
+                .or_default()
+                .push((id, control)),
+            Err(error) => self.report(AudioError::Stream {
+                channel: channel.clone(),
+                path: path.to_path_buf(),
+                message: format!("{:?}", error),
+            }),
+        }
+    }
+
+    fn stop_streams(&mut self, channel_id: &AudioChannel) {
+        if let Some(streams) = self.streams.remove(channel_id) {
+            if let Some(manager) = self.manager.as_mut() {
+                for (id, _) in streams {
+                    if let Err(error) = manager.remove_stream(id) {
+                        warn!(
+                            "Failed to stop stream in channel {:?}: {:?}",
+                            channel_id, error
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_stream_volume(&self, channel_id: &AudioChannel) {
+        if let Some(streams) = self.streams.get(channel_id) {
+            let volume = self.channel_volume(channel_id);
+            for (_, control) in streams {
+                control.set_volume(volume);
+            }
+        }
+    }
+
+    fn stop(&mut self, channel_id: AudioChannel) {
+        self.queues.remove(&channel_id);
+        self.stop_streams(&channel_id);
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            for mut instance in instances.drain(..) {
+                if let Err(error) = instance.handle.stop(StopInstanceSettings::default()) {
+                    warn!(
+                        "Failed to stop instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn stop_instance(&mut self, channel_id: AudioChannel, instance_id: u64) {
+        let instances = match self.instances.get_mut(&channel_id) {
+            Some(instances) => instances,
+            None => return,
+        };
+        if let Some(index) = instances
+            .iter()
+            .position(|instance| instance.id == instance_id)
+        {
+            let mut instance = instances.remove(index);
+            if let Err(error) = instance.handle.stop(StopInstanceSettings::default()) {
+                warn!(
+                    "Failed to stop instance in channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
+        }
+    }
+
+    fn pause_instance(&mut self, channel_id: AudioChannel, instance_id: u64) {
+        let instance = match self.instances.get_mut(&channel_id).and_then(|instances| {
+            instances
+                .iter_mut()
+                .find(|instance| instance.id == instance_id)
+        }) {
+            Some(instance) => instance,
+            None => return,
+        };
+        if instance.handle.state() == InstanceState::Stopped {
+            return;
+        }
+        // Paused on purpose, so resuming all channels keeps it paused
+        instance.paused_by_pause_all = false;
+        if let Err(error) = instance.handle.pause(PauseInstanceSettings::default()) {
+            warn!(
+                "Failed to pause instance in channel {:?}: {:?}",
+                channel_id, error
+            );
+        }
+    }
+
+    fn resume_instance(&mut self, channel_id: AudioChannel, instance_id: u64) {
+        let instance = match self.instances.get_mut(&channel_id).and_then(|instances| {
+            instances
+                .iter_mut()
+                .find(|instance| instance.id == instance_id)
+        }) {
+            Some(instance) => instance,
+            None => return,
+        };
+        if instance.handle.state() == InstanceState::Stopped {
+            return;
+        }
+        instance.paused_by_pause_all = false;
+        if let Err(error) = instance.handle.resume(ResumeInstanceSettings::default()) {
+            warn!(
+                "Failed to resume instance in channel {:?}: {:?}",
+                channel_id, error
+            );
+        }
+    }
+
+    fn stop_with_fade(&mut self, channel_id: AudioChannel, fade_tween: Tween) {
+        self.queues.remove(&channel_id);
+        // Streams do not support fading
+        self.stop_streams(&channel_id);
+        // The instances stay in the channel until they finished fading out,
+        // so that commands queued during the fade still reach them
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            let settings = StopInstanceSettings::new().fade_tween(fade_tween);
+            for instance in instances.iter_mut() {
+                instance.stopped_by_command = true;
+                if let Err(error) = instance.handle.stop(settings) {
+                    warn!(
+                        "Failed to stop instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn stop_all(&mut self) {
+        for channel_id in self.playing_channels() {
+            self.stop(channel_id);
+        }
+    }
+
+    fn stop_all_with_fade(&mut self, fade_tween: Tween) {
+        for channel_id in self.playing_channels() {
+            self.stop_with_fade(channel_id, fade_tween);
+        }
+    }
+
+    /// All channels with instances, streams or queues
+    fn playing_channels(&self) -> HashSet<AudioChannel> {
+        self.instances
+            .keys()
+            .chain(self.streams.keys())
+            .chain(self.queues.keys())
+            .cloned()
+            .collect()
+    }
+
+    fn pause(&mut self, channel_id: AudioChannel, settings: PauseInstanceSettings) {
+        self.channels.entry(channel_id.clone()).or_default().paused = true;
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            for instance in instances.iter_mut() {
+                if let Err(error) = instance.handle.pause(settings) {
+                    warn!(
+                        "Failed to pause instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn resume(&mut self, channel_id: AudioChannel, settings: ResumeInstanceSettings) {
+        self.channels.entry(channel_id.clone()).or_default().paused = false;
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            for instance in instances.iter_mut() {
+                instance.paused_by_pause_all = false;
+                if let Err(error) = instance.handle.resume(settings) {
+                    warn!(
+                        "Failed to resume instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn toggle_pause(&mut self, channel_id: AudioChannel) {
+        let paused = self
+            .channels
+            .get(&channel_id)
+            .map(|channel_state| channel_state.paused);
+        if paused == Some(true) {
+            self.resume(channel_id, ResumeInstanceSettings::default());
+        } else {
+            self.pause(channel_id, PauseInstanceSettings::default());
+        }
+    }
+
+    /// Pause all playing instances in all channels
+    ///
+    /// Instances that were already paused are not touched, so that
+    /// `resume_all` keeps them paused.
+    fn pause_all(&mut self) {
+        for (channel_id, instances) in self.instances.iter_mut() {
+            for instance in instances.iter_mut() {
+                if instance.handle.state() != InstanceState::Playing {
+                    continue;
+                }
+                instance.paused_by_pause_all = true;
+                if let Err(error) = instance.handle.pause(PauseInstanceSettings::default()) {
+                    warn!(
+                        "Failed to pause instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn resume_all(&mut self) {
+        for (channel_id, instances) in self.instances.iter_mut() {
+            for instance in instances.iter_mut() {
+                if !instance.paused_by_pause_all {
+                    continue;
+                }
+                instance.paused_by_pause_all = false;
+                if let Err(error) = instance.handle.resume(ResumeInstanceSettings::default()) {
+                    warn!(
+                        "Failed to resume instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    /// Create the command to play the next audio source in the queue of the channel
+    fn play_next_in_queue(
+        &mut self,
+        channel_id: &AudioChannel,
+        audio: &Audio,
+    ) -> Option<QueuedAudioCommand> {
+        let queue = self.queues.get_mut(channel_id)?;
+        queue.current = None;
+        let audio_source = queue.next_audio_source()?;
+        let (command, instance_id) = audio.create_queue_track_command(audio_source, channel_id);
+        queue.current = Some(instance_id);
+        Some(QueuedAudioCommand::Channel(command, channel_id.clone()))
+    }
+
+    /// Start the next audio source in all queues whose current audio source finished
+    fn advance_queues(&mut self, finished: &[AudioFinished], audio: &Audio) {
+        for event in finished {
+            let is_current = self
+                .queues
+                .get(&event.channel)
+                .and_then(|queue| queue.current)
+                == Some(event.instance.id);
+            if !is_current {
+                continue;
+            }
+            if let Some(command) = self.play_next_in_queue(&event.channel, audio) {
+                audio.commands.write().push_back(command);
+            }
+        }
+    }
+
+    fn seek_to(&mut self, channel_id: AudioChannel, position: f64) {
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            for instance in instances.iter_mut() {
+                if let Err(error) = instance.seek_to(position) {
+                    warn!(
+                        "Failed to seek instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    /// Seek all active instances in the channel to the start
+    ///
+    /// Instances that fade out are left alone. Returns a command to play the last played audio again if no instance is active.
+    fn restart(&mut self, channel_id: AudioChannel, audio: &Audio) -> Option<QueuedAudioCommand> {
+        let active = self
+            .instances
+            .get(&channel_id)
+            .into_iter()
+            .flatten()
+            .any(|instance| !instance.is_stopping());
+        if active {
+            for instance in self.instances.get_mut(&channel_id).into_iter().flatten() {
+                if instance.is_stopping() {
+                    // Fading out instances keep fading out instead of playing again
+                    continue;
+                }
+                // Reversed instances start at the end of the sound
+                let start = if instance.reversed {
+                    instance.duration
+                } else {
+                    0.0
+                };
+                // Not wrapped, since the end of a looped sound is the start of reversed playback
+                match instance.handle.seek_to(start) {
+                    Ok(()) => instance.position = start,
+                    Err(error) => warn!(
+                        "Failed to restart instance in channel {:?}: {:?}",
+                        channel_id, error
+                    ),
+                }
+            }
+            return None;
+        }
+        let settings = self.last_played.get(&channel_id)?.clone();
+        Some(QueuedAudioCommand::Channel(
+            audio.create_restart_command(settings, &channel_id),
+            channel_id,
+        ))
+    }
+
+    fn seek_by(&mut self, channel_id: AudioChannel, offset: f64) {
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            for instance in instances.iter_mut() {
+                if let Err(error) = instance.seek_to(instance.position + offset) {
+                    warn!(
+                        "Failed to seek instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn set_volume(&mut self, channel_id: AudioChannel, volume: f64, tween: Option<Tween>) {
+        self.channels.entry(channel_id.clone()).or_default().volume = volume;
+        self.apply_channel_volume(&channel_id, tween);
+    }
+
+    /// The volume that is actually applied to the instances and streams of a channel
+    ///
+    /// While another channel is soloed, this is zero.
+    fn channel_volume(&self, channel_id: &AudioChannel) -> f64 {
+        if matches!(&self.solo, Some(solo) if solo != channel_id) {
+            return 0.0;
+        }
+        self.channels
+            .get(channel_id)
+            .map_or(1.0, |channel_state| channel_state.effective_volume())
+    }
+
+    /// Change the volume of the master track, which all audio plays through
+    fn set_master_volume(&mut self, volume: f64, tween: Option<Tween>) {
+        self.master_volume = volume.max(0.0);
+        if let Some(master_gain) = self.master_gain.as_mut() {
+            if let Err(error) = master_gain.set(self.master_volume, instant_or(tween)) {
+                warn!("Failed to change the master volume: {:?}", error);
+            }
+        }
+    }
+
+    /// Silence all channels except the given one, or none if it is `None`
+    fn set_solo(&mut self, solo: Option<AudioChannel>) {
+        if self.solo == solo {
+            return;
+        }
+        self.solo = solo;
+        let channels: HashSet<AudioChannel> = self
+            .channels
+            .keys()
+            .cloned()
+            .chain(self.playing_channels())
+            .collect();
+        for channel_id in channels {
+            self.apply_channel_volume(&channel_id, None);
+        }
+    }
+
+    fn set_muted(&mut self, channel_id: AudioChannel, muted: bool) {
+        self.channels.entry(channel_id.clone()).or_default().muted = muted;
+        self.apply_channel_volume(&channel_id, None);
+    }
+
+    /// Apply the effective volume of the channel to its instances and streams
+    fn apply_channel_volume(&mut self, channel_id: &AudioChannel, tween: Option<Tween>) {
+        let effective_volume = self.channel_volume(channel_id);
+        self.update_stream_volume(channel_id);
+        if let Some(parameter) = self.channel_volume_parameter(channel_id) {
+            if let Err(error) = parameter.set(effective_volume, instant_or(tween)) {
+                warn!(
+                    "Failed to set volume for channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
+        }
+    }
+
+    fn add_ducking(
+        &mut self,
+        ducked: AudioChannel,
+        trigger: AudioChannel,
+        settings: DuckingSettings,
+    ) {
+        self.remove_ducking(&ducked, &trigger);
+        self.duckings.push(Ducking {
+            ducked,
+            trigger,
+            settings,
+        });
+    }
+
+    fn remove_ducking(&mut self, ducked: &AudioChannel, trigger: &AudioChannel) {
+        let removed = self
+            .duckings
+            .iter()
+            .position(|ducking| &ducking.ducked == ducked && &ducking.trigger == trigger)
+            .map(|index| self.duckings.remove(index));
+        if let Some(removed) = removed {
+            if !self
+                .duckings
+                .iter()
+                .any(|ducking| &ducking.ducked == ducked)
+            {
+                self.set_duck_factor(ducked, 1.0, removed.settings.release);
+            }
+        }
+    }
+
+    /// Duck or restore channels based on whether their trigger channels are playing
+    fn update_ducking(&mut self) {
+        let mut targets: HashMap<AudioChannel, (f64, Tween)> = HashMap::default();
+        for ducking in self.duckings.iter() {
+            let triggered = self
+                .instances
+                .get(&ducking.trigger)
+                .into_iter()
+                .flatten()
+                .any(|instance| instance.handle.state() == InstanceState::Playing);
+            let target = targets
+                .entry(ducking.ducked.clone())
+                .or_insert((1.0, ducking.settings.release));
+            let factor = 1.0 - ducking.settings.amount;
+            if triggered && factor < target.0 {
+                *target = (factor, ducking.settings.attack);
+            }
+        }
+        for (channel, (duck_factor, tween)) in targets {
+            self.set_duck_factor(&channel, duck_factor, tween);
+        }
+    }
+
+    fn set_duck_factor(&mut self, channel: &AudioChannel, duck_factor: f64, tween: Tween) {
+        let channel_state = self.channels.entry(channel.clone()).or_default();
+        if (channel_state.duck_factor - duck_factor).abs() < f64::EPSILON {
+            return;
+        }
+        channel_state.duck_factor = duck_factor;
+        self.apply_channel_volume(channel, Some(tween));
+    }
+
+    fn set_instance_volume(&mut self, channel_id: AudioChannel, instance_id: u64, volume: f64) {
+        let spatial_volume = match self.find_instance(&channel_id, instance_id) {
+            Some(instance) => {
+                instance.volume = volume;
+                instance.spatial_volume
+            }
+            None => return,
+        };
+        self.apply_instance_volume(&channel_id, instance_id, volume * spatial_volume);
+    }
+
+    /// Set the attenuation and panning that the emitter of the instance is heard with
+    ///
+    /// The first values of an instance apply directly. Later ones are approached
+    /// over time, see [`SPATIAL_SMOOTHING`].
+    fn set_instance_spatial(
+        &mut self,
+        channel_id: AudioChannel,
+        instance_id: u64,
+        spatial_volume: f64,
+        panning: f64,
+    ) {
+        let volume = match self.find_instance(&channel_id, instance_id) {
+            Some(instance) => {
+                if instance
+                    .spatial_target
+                    .replace((spatial_volume, panning))
+                    .is_some()
+                {
+                    return;
+                }
+                instance.spatial_volume = spatial_volume;
+                instance.spatial_panning = panning;
+                instance.volume
+            }
+            None => return,
+        };
+        self.apply_instance_volume(&channel_id, instance_id, volume * spatial_volume);
+        self.set_instance_panning(channel_id, instance_id, panning);
+    }
+
+    /// Move the spatial volume and panning of instances towards the values of their emitters
+    fn update_spatial(&mut self, delta: f64) {
+        let step = 1.0 - (-delta / SPATIAL_SMOOTHING).exp();
+        let mut changed = vec![];
+        for (channel, instances) in self.instances.iter_mut() {
+            for instance in instances.iter_mut() {
+                let (volume, panning) = match instance.spatial_target {
+                    Some(target) => target,
+                    None => continue,
+                };
+                if instance.spatial_volume == volume && instance.spatial_panning == panning {
+                    continue;
+                }
+                instance.spatial_volume = approach(instance.spatial_volume, volume, step);
+                instance.spatial_panning = approach(instance.spatial_panning, panning, step);
+                changed.push((
+                    channel.clone(),
+                    instance.id,
+                    instance.volume * instance.spatial_volume,
+                    instance.spatial_panning,
+                ));
+            }
+        }
+        for (channel, instance_id, volume, panning) in changed {
+            self.apply_instance_volume(&channel, instance_id, volume);
+            self.set_instance_panning(channel, instance_id, panning);
         }
     }
-}
 
-impl AudioOutput {
-    fn get_or_create_sound(
+    /// Find an instance by id
+    ///
+    /// A restarted instance shares its id with the stopped one until that is pruned,
+    /// so the latest instance is the one that is found.
+    fn find_instance(
         &mut self,
-        audio_source: &AudioSource,
-        audio_source_handle: Handle<AudioSource>,
-    ) -> SoundHandle {
-        if let Some(handle) = self.sounds.get(&audio_source_handle) {
-            return handle.clone();
+        channel_id: &AudioChannel,
+        instance_id: u64,
+    ) -> Option<&mut AudioInstance> {
+        self.instances.get_mut(channel_id).and_then(|instances| {
+            instances
+                .iter_mut()
+                .rev()
+                .find(|instance| instance.id == instance_id)
+        })
+    }
+
+    /// Apply the effective volume of an instance, which the channel volume is multiplied with
+    fn apply_instance_volume(&mut self, channel_id: &AudioChannel, instance_id: u64, volume: f64) {
+        let value = self.instance_volume(channel_id, volume);
+        if let Some(instance) = self.find_instance(channel_id, instance_id) {
+            if let Err(error) = instance.handle.set_volume(value) {
+                warn!(
+                    "Failed to set volume for instance in channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
         }
+    }
 
-        let sound = audio_source.sound.clone();
-        let handle = self.manager.add_sound(sound).unwrap();
-        self.sounds.insert(audio_source_handle, handle.clone());
-        handle
+    fn set_instance_panning(&mut self, channel_id: AudioChannel, instance_id: u64, panning: f64) {
+        if let Some(instance) = self.find_instance(&channel_id, instance_id) {
+            if let Err(error) = instance.handle.set_panning(panning) {
+                warn!(
+                    "Failed to set panning for instance in channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
+        }
     }
 
-    fn play_arrangement(
+    fn set_panning(&mut self, channel_id: AudioChannel, panning: f64, tween: Option<Tween>) {
+        // Tweens need a parameter, which starts at the current panning of the channel
+        if tween.is_some() {
+            self.channel_panning_parameter(&channel_id);
+        }
+        self.channels.entry(channel_id.clone()).or_default().panning = panning;
+        if let Some(parameter) = self
+            .channels
+            .get_mut(&channel_id)
+            .and_then(|channel_state| channel_state.panning_parameter.as_mut())
+        {
+            if let Err(error) = parameter.set(panning, instant_or(tween)) {
+                warn!(
+                    "Failed to set panning for channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
+        }
+        let value = self.channel_panning(&channel_id);
+        if let Some(instances) = self.instances.get_mut(&channel_id) {
+            // The panning of instances with an emitter follows the emitter
+            for instance in instances
+                .iter_mut()
+                .filter(|instance| instance.spatial_target.is_none())
+            {
+                instance.panning = None;
+                if let Err(error) = instance.handle.set_panning(value) {
+                    warn!(
+                        "Failed to set panning for instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    fn set_pitch(&mut self, channel_id: AudioChannel, pitch: f64, tween: Option<Tween>) {
+        // Tweens need a parameter, which starts at the current pitch of the channel
+        if tween.is_some() {
+            self.channel_pitch_parameter(&channel_id);
+        }
+        self.channels.entry(channel_id.clone()).or_default().pitch = pitch;
+        if let Some(parameter) = self
+            .channels
+            .get_mut(&channel_id)
+            .and_then(|channel_state| channel_state.pitch_parameter.as_mut())
+        {
+            if let Err(error) = parameter.set(pitch, instant_or(tween)) {
+                warn!(
+                    "Failed to set pitch for channel {:?}: {:?}",
+                    channel_id, error
+                );
+            }
+        }
+        let pitch_factors: Vec<(u64, f64)> = self
+            .instances
+            .get(&channel_id)
+            .into_iter()
+            .flatten()
+            .map(|instance| (instance.id, instance.pitch_factor))
+            .collect();
+        for (instance_id, pitch_factor) in pitch_factors {
+            let value = self.instance_pitch(&channel_id, pitch_factor);
+            let instance = self.instances.get_mut(&channel_id).and_then(|instances| {
+                instances
+                    .iter_mut()
+                    .find(|instance| instance.id == instance_id)
+            });
+            if let Some(instance) = instance {
+                if let Err(error) = instance.handle.set_pitch(value) {
+                    warn!(
+                        "Failed to set pitch for instance in channel {:?}: {:?}",
+                        channel_id, error
+                    );
+                }
+            }
+        }
+    }
+
+    pub(crate) fn run_queued_audio_commands(
         &mut self,
-        mut arrangement_handle: ArrangementHandle,
-        channel: &AudioChannel,
+        audio_sources: &Assets<AudioSource>,
+        audio: &mut Audio,
     ) {
-        let play_result = arrangement_handle.play(Default::default());
-        if let Err(error) = play_result {
-            println!("Failed to play arrangement: {:?}", error);
+        let mut commands = audio.commands.write();
+        if !self.is_initialized() {
+            // Keep the commands to run them in order once the audio manager exists
+            return;
+        }
+        if !self.is_available() {
+            // Without an audio device, there is nothing to apply the commands to
+            commands.clear();
             return;
         }
-        let mut instance_handle = play_result.unwrap();
-        if let Some(channel_state) = self.channels.get(&channel) {
-            if let Err(error) = instance_handle.set_volume(channel_state.volume) {
-                println!("Failed to set volume for instance: {:?}", error);
+        coalesce_setters(&mut commands);
+        // At least one command runs per frame, so the queue can not grow forever
+        let limit = self
+            .settings
+            .max_commands_per_frame
+            .map(|limit| limit.max(1));
+        if let Some(limit) = limit {
+            let exceeded = commands.len() > limit;
+            if exceeded && !self.command_limit_exceeded {
+                warn!(
+                    "{} audio commands are queued, but only {} run per frame. The rest are delayed.",
+                    commands.len(),
+                    limit
+                );
             }
-            if let Err(error) = instance_handle.set_pitch(channel_state.pitch) {
-                println!("Failed to set volume for instance: {:?}", error);
+            self.command_limit_exceeded = exceeded;
+        }
+        // Once a command in a channel has to wait for its audio source to load,
+        // all later commands in that channel wait as well to keep their order
+        let mut deferred = VecDeque::new();
+        let mut blocked = BlockedCommands::default();
+        let mut run_commands = 0;
+        while !matches!(limit, Some(limit) if run_commands >= limit) {
+            let audio_command = match commands.pop_front() {
+                Some(command) => command,
+                None => break,
+            };
+            let ready = Self::is_ready(&audio_command, audio_sources);
+            if !ready && self.load_timed_out(&audio_command, audio_sources) {
+                continue;
             }
-            if let Err(error) = instance_handle.set_panning(channel_state.panning) {
-                println!("Failed to set volume for instance: {:?}", error);
+            if !ready || blocked.is_blocked(&audio_command) {
+                // audio sources haven't loaded yet. Add it back to the queue
+                blocked.block(&audio_command);
+                deferred.push_back(audio_command);
+                continue;
             }
+            self.run_queued_command(&audio_command, audio_sources, audio, &mut commands);
+            run_commands += 1;
         }
-        if let Some(instance_handles) = self.instances.get_mut(&channel) {
-            instance_handles.push(instance_handle);
-        } else {
-            self.instances
-                .insert(channel.clone(), vec![instance_handle]);
+        // Commands beyond the limit run next frame, after the deferred ones that came before them
+        deferred.append(&mut commands);
+        *commands = deferred;
+        // Only keep waiting for sources that a queued command still needs
+        let mut waiting = HashSet::new();
+        for audio_command in commands.iter() {
+            Self::collect_missing_sources(audio_command, audio_sources, &mut waiting);
         }
+        self.load_waits.retain(|handle, _| waiting.contains(handle));
+        for handle in waiting {
+            self.load_waits.entry(handle).or_insert(0.0);
+        }
+        self.preload_loaded_sources(audio_sources);
     }
 
-    fn play(&mut self, sound_handle: &SoundHandle, channel: &AudioChannel) -> ArrangementHandle {
-        let mut arrangement = Arrangement::new(ArrangementSettings::new().cooldown(0.0));
-        arrangement.add_clip(SoundClip::new(sound_handle, 0.0));
-        let arrangement_handle = self.manager.add_arrangement(arrangement).unwrap();
-
-        self.play_arrangement(arrangement_handle.clone(), channel);
-        arrangement_handle
+    fn update_load_waits(&mut self, delta: f64) {
+        for wait in self.load_waits.values_mut() {
+            *wait += delta;
+        }
     }
 
-    fn play_looped(
+    /// Whether the command waited too long for its audio sources and is dropped
+    fn load_timed_out(
         &mut self,
-        sound_handle: &SoundHandle,
-        channel: &AudioChannel,
-    ) -> ArrangementHandle {
-        let arrangement = Arrangement::new_loop(sound_handle, Default::default());
-        let arrangement_handle = self.manager.add_arrangement(arrangement).unwrap();
-
-        self.play_arrangement(arrangement_handle.clone(), channel);
-        arrangement_handle
+        audio_command: &QueuedAudioCommand,
+        audio_sources: &Assets<AudioSource>,
+    ) -> bool {
+        let timeout = match self.settings.load_timeout {
+            Some(timeout) => timeout,
+            None => return false,
+        };
+        let mut missing = HashSet::new();
+        Self::collect_missing_sources(audio_command, audio_sources, &mut missing);
+        let timed_out: Vec<_> = missing
+            .into_iter()
+            .filter(|handle| {
+                matches!(self.load_waits.get(handle), Some(wait) if *wait >= timeout.as_secs_f64())
+            })
+            .collect();
+        let channel = match audio_command {
+            QueuedAudioCommand::Channel(_, channel_id) => Some(channel_id.clone()),
+            QueuedAudioCommand::Global(_) => None,
+        };
+        let dropped = !timed_out.is_empty();
+        for audio_source in timed_out {
+            self.report(AudioError::LoadTimeout {
+                audio_source,
+                channel: channel.clone(),
+                timeout,
+            });
+        }
+        dropped
     }
 
-    fn stop(&mut self, channel_id: AudioChannel) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for mut instance in instances.drain(..) {
-                if let Err(error) = instance.stop(StopInstanceSettings::default()) {
-                    println!("Failed to stop instance: {:?}", error);
+    /// Add the audio sources that the command plays and that are not loaded yet
+    fn collect_missing_sources(
+        audio_command: &QueuedAudioCommand,
+        audio_sources: &Assets<AudioSource>,
+        missing: &mut HashSet<Handle<AudioSource>>,
+    ) {
+        match audio_command {
+            QueuedAudioCommand::Channel(audio_command, _) => {
+                Self::collect_missing_play_sources(audio_command, audio_sources, missing);
+            }
+            QueuedAudioCommand::Global(GlobalAudioCommands::Batch(batch)) => {
+                for audio_command in batch.iter() {
+                    Self::collect_missing_sources(audio_command, audio_sources, missing);
                 }
             }
+            QueuedAudioCommand::Global(_) => (),
         }
     }
 
-    fn pause(&mut self, channel_id: AudioChannel) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for instance in instances.iter_mut() {
-                if let Err(error) = instance.pause(PauseInstanceSettings::default()) {
-                    println!("Failed to pause instance: {:?}", error);
+    fn collect_missing_play_sources(
+        audio_command: &AudioCommands,
+        audio_sources: &Assets<AudioSource>,
+        missing: &mut HashSet<Handle<AudioSource>>,
+    ) {
+        match audio_command {
+            AudioCommands::Play(play_settings, _) => {
+                for handle in Some(&play_settings.source)
+                    .into_iter()
+                    .chain(play_settings.intro.iter())
+                {
+                    if audio_sources.get(handle).is_none() {
+                        missing.insert(handle.clone());
+                    }
                 }
             }
-        }
-    }
-
-    fn resume(&mut self, channel_id: AudioChannel) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for instance in instances.iter_mut() {
-                if let Err(error) = instance.resume(ResumeInstanceSettings::default()) {
-                    println!("Failed to resume instance: {:?}", error);
+            AudioCommands::PlaySynced(plays) => {
+                for audio_command in plays.iter() {
+                    Self::collect_missing_play_sources(audio_command, audio_sources, missing);
                 }
             }
+            _ => (),
         }
     }
 
-    fn set_volume(&mut self, channel_id: AudioChannel, volume: f64) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for instance in instances.iter_mut() {
-                if let Err(error) = instance.set_volume(volume) {
-                    println!("Failed to set volume for instance: {:?}", error);
+    fn preload_loaded_sources(&mut self, audio_sources: &Assets<AudioSource>) {
+        let limit = self
+            .settings
+            .max_preloads_per_frame
+            .map(|limit| limit.max(1));
+        let mut added = 0;
+        let preloads = std::mem::take(&mut self.preloads);
+        for handle in preloads {
+            if self.sounds.contains_key(&handle) {
+                continue;
+            }
+            match audio_sources.get(&handle) {
+                Some(audio_source) if !matches!(limit, Some(limit) if added >= limit) => {
+                    self.get_or_create_sound(audio_source, handle);
+                    added += 1;
                 }
+                _ => self.preloads.push(handle),
             }
         }
-        if let Some(mut channel_state) = self.channels.get_mut(&channel_id) {
-            channel_state.volume = volume;
-        } else {
-            let mut channel_state = ChannelState::default();
-            channel_state.volume = volume;
-            self.channels.insert(channel_id, channel_state);
-        }
     }
 
-    fn set_panning(&mut self, channel_id: AudioChannel, panning: f64) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for instance in instances.iter_mut() {
-                if let Err(error) = instance.set_panning(panning) {
-                    println!("Failed to set panning for instance: {:?}", error);
-                }
+    /// Check if all audio sources that the command plays are loaded
+    fn is_ready(audio_command: &QueuedAudioCommand, audio_sources: &Assets<AudioSource>) -> bool {
+        match audio_command {
+            QueuedAudioCommand::Channel(audio_command, _) => {
+                Self::is_play_ready(audio_command, audio_sources)
             }
-        }
-        if let Some(mut channel_state) = self.channels.get_mut(&channel_id) {
-            channel_state.panning = panning;
-        } else {
-            let mut channel_state = ChannelState::default();
-            channel_state.panning = panning;
-            self.channels.insert(channel_id, channel_state);
+            QueuedAudioCommand::Global(GlobalAudioCommands::Batch(batch)) => batch
+                .iter()
+                .all(|audio_command| Self::is_ready(audio_command, audio_sources)),
+            QueuedAudioCommand::Global(_) => true,
         }
     }
 
-    fn set_pitch(&mut self, channel_id: AudioChannel, pitch: f64) {
-        if let Some(instances) = self.instances.get_mut(&channel_id) {
-            for instance in instances.iter_mut() {
-                if let Err(error) = instance.set_pitch(pitch) {
-                    println!("Failed to set pitch for instance: {:?}", error);
-                }
+    fn is_play_ready(audio_command: &AudioCommands, audio_sources: &Assets<AudioSource>) -> bool {
+        match audio_command {
+            AudioCommands::Play(play_settings, _) => {
+                audio_sources.get(&play_settings.source).is_some()
+                    && play_settings
+                        .intro
+                        .iter()
+                        .all(|intro| audio_sources.get(intro).is_some())
             }
-        }
-        if let Some(mut channel_state) = self.channels.get_mut(&channel_id) {
-            channel_state.pitch = pitch;
-        } else {
-            let mut channel_state = ChannelState::default();
-            channel_state.pitch = pitch;
-            self.channels.insert(channel_id, channel_state);
+            AudioCommands::PlaySynced(plays) => plays
+                .iter()
+                .all(|audio_command| Self::is_play_ready(audio_command, audio_sources)),
+            _ => true,
         }
     }
 
-    pub(crate) fn run_queued_audio_commands(
+    fn run_command(
         &mut self,
+        audio_command: &AudioCommands,
+        channel_id: AudioChannel,
         audio_sources: &Assets<AudioSource>,
-        audio: &mut Audio,
+        audio: &Audio,
+        commands: &mut VecDeque<QueuedAudioCommand>,
     ) {
-        let mut commands = audio.commands.write();
-        let len = commands.len();
-        let mut i = 0;
-        while i < len {
-            let (audio_command, channel_id) = commands.pop_back().unwrap();
-            match &audio_command {
-                AudioCommands::Play(play_settings) => {
-                    if let Some(audio_source) = audio_sources.get(&play_settings.source) {
-                        let sound_handle =
-                            self.get_or_create_sound(audio_source, play_settings.source.clone());
-                        if self.arrangements.contains_key(play_settings) {
-                            self.play_arrangement(
-                                self.arrangements.get(play_settings).unwrap().clone(),
-                                &channel_id,
-                            );
-                        } else {
-                            let arrangement_handle = if play_settings.looped {
-                                self.play_looped(&sound_handle, &channel_id)
-                            } else {
-                                self.play(&sound_handle, &channel_id)
-                            };
-                            self.arrangements
-                                .insert(play_settings.clone(), arrangement_handle);
-                        }
+        match audio_command {
+            AudioCommands::Play(play_settings, instance_settings) => {
+                let intro_source = match &play_settings.intro {
+                    Some(intro) => audio_sources.get(intro).map(Some),
+                    None => Some(None),
+                };
+                if let (Some(audio_source), Some(intro_source)) =
+                    (audio_sources.get(&play_settings.source), intro_source)
+                {
+                    let cooldown_key = (play_settings.clone(), channel_id.clone());
+                    let skipped = (instance_settings.skip_if_playing
+                        && self.is_playing_source(&channel_id, &play_settings.source))
+                        || self.cooldowns.contains_key(&cooldown_key);
+                    let arrangement_handle = if skipped {
+                        None
                     } else {
-                        // audio source hasn't loaded yet. Add it back to the queue
-                        commands.push_front((audio_command, channel_id));
+                        self.get_or_create_arrangement(
+                            play_settings,
+                            audio_source,
+                            intro_source,
+                            &channel_id,
+                        )
+                    };
+                    if let Some(arrangement_handle) = arrangement_handle {
+                        self.play_arrangement(
+                            arrangement_handle,
+                            play_settings,
+                            &channel_id,
+                            instance_settings,
+                        );
+                        let cooldown = play_settings.cooldown.seconds();
+                        if cooldown > 0.0 {
+                            self.cooldowns.insert(cooldown_key, cooldown);
+                        }
                     }
                 }
-                AudioCommands::Stop => {
-                    self.stop(channel_id);
+            }
+            AudioCommands::PlayStreamed(path, looped) => {
+                self.play_streamed(path, *looped, &channel_id);
+            }
+            AudioCommands::PlayQueue(audio_sources, looped) => {
+                self.stop(channel_id.clone());
+                self.queues.insert(
+                    channel_id.clone(),
+                    AudioQueue {
+                        audio_sources: audio_sources.clone(),
+                        looped: *looped,
+                        ..Default::default()
+                    },
+                );
+                if let Some(command) = self.play_next_in_queue(&channel_id, audio) {
+                    commands.push_back(command);
+                }
+            }
+            AudioCommands::QueueNext(audio_source) => {
+                let queue = self.queues.entry(channel_id.clone()).or_default();
+                queue.audio_sources.push(audio_source.clone());
+                if queue.current.is_none() {
+                    if let Some(command) = self.play_next_in_queue(&channel_id, audio) {
+                        commands.push_back(command);
+                    }
+                }
+            }
+            AudioCommands::ClearQueue => {
+                self.queues.remove(&channel_id);
+            }
+            AudioCommands::Stop => {
+                self.stop(channel_id);
+            }
+            AudioCommands::StopInstance(instance_id) => {
+                self.stop_instance(channel_id, *instance_id);
+            }
+            AudioCommands::PauseInstance(instance_id) => {
+                self.pause_instance(channel_id, *instance_id);
+            }
+            AudioCommands::ResumeInstance(instance_id) => {
+                self.resume_instance(channel_id, *instance_id);
+            }
+            AudioCommands::StopWithFade(fade_tween) => {
+                self.stop_with_fade(channel_id, *fade_tween);
+            }
+            AudioCommands::Pause => {
+                self.pause(channel_id, PauseInstanceSettings::default());
+            }
+            AudioCommands::PauseWithFade(fade_tween) => {
+                self.pause(
+                    channel_id,
+                    PauseInstanceSettings::new().fade_tween(*fade_tween),
+                );
+            }
+            AudioCommands::Resume => {
+                self.resume(channel_id, ResumeInstanceSettings::default());
+            }
+            AudioCommands::ResumeWithFade(fade_tween) => {
+                self.resume(
+                    channel_id,
+                    ResumeInstanceSettings::new().fade_tween(*fade_tween),
+                );
+            }
+            AudioCommands::TogglePause => {
+                self.toggle_pause(channel_id);
+            }
+            AudioCommands::SeekTo(position) => {
+                self.seek_to(channel_id, *position);
+            }
+            AudioCommands::SeekBy(offset) => {
+                self.seek_by(channel_id, *offset);
+            }
+            AudioCommands::Restart => {
+                if let Some(command) = self.restart(channel_id, audio) {
+                    commands.push_back(command);
+                }
+            }
+            AudioCommands::SetVolume(volume) => {
+                self.set_volume(channel_id, *volume as f64, None);
+            }
+            AudioCommands::SetMuted(muted) => {
+                self.set_muted(channel_id, *muted);
+            }
+            AudioCommands::SetSolo => {
+                self.set_solo(Some(channel_id));
+            }
+            AudioCommands::SetVolumeTween(volume, tween) => {
+                self.set_volume(channel_id, *volume as f64, Some(*tween));
+            }
+            AudioCommands::SetInstanceVolume(instance_id, volume) => {
+                self.set_instance_volume(channel_id, *instance_id, *volume as f64);
+            }
+            AudioCommands::SetInstancePanning(instance_id, panning) => {
+                if let Some(instance) = self.find_instance(&channel_id, *instance_id) {
+                    instance.panning = Some(*panning as f64);
+                }
+                self.set_instance_panning(channel_id, *instance_id, *panning as f64);
+            }
+            AudioCommands::SetInstanceSpatial(instance_id, volume, panning) => {
+                self.set_instance_spatial(
+                    channel_id,
+                    *instance_id,
+                    *volume as f64,
+                    *panning as f64,
+                );
+            }
+            AudioCommands::AddDucking(trigger, settings) => {
+                self.add_ducking(channel_id, trigger.clone(), *settings);
+            }
+            AudioCommands::RemoveDucking(trigger) => {
+                self.remove_ducking(&channel_id, trigger);
+            }
+            AudioCommands::SetPanning(panning) => {
+                self.set_panning(channel_id, *panning as f64, None);
+            }
+            AudioCommands::SetPanningTween(panning, tween) => {
+                self.set_panning(channel_id, *panning as f64, Some(*tween));
+            }
+            AudioCommands::SetPitch(pitch) => {
+                self.set_pitch(channel_id, *pitch as f64, None);
+            }
+            AudioCommands::SetPitchTween(pitch, tween) => {
+                self.set_pitch(channel_id, *pitch as f64, Some(*tween));
+            }
+            AudioCommands::SetTrack(track) => {
+                self.set_channel_track(channel_id, track.clone());
+            }
+            AudioCommands::SetMaxVoices(max_voices) => {
+                self.channels.entry(channel_id).or_default().max_voices = *max_voices;
+            }
+            AudioCommands::StartMetronome(tempo, intervals) => {
+                self.start_metronome(channel_id, *tempo, intervals);
+            }
+            AudioCommands::StopMetronome => {
+                self.stop_metronome(&channel_id);
+            }
+            AudioCommands::SetMetronomeTempo(tempo) => {
+                if let Some(metronome) = self.metronomes.get_mut(&channel_id) {
+                    match metronome.handle.set_tempo(Tempo(*tempo)) {
+                        Ok(()) => metronome.tempo = *tempo,
+                        Err(error) => warn!(
+                            "Failed to set the metronome tempo of channel {:?}: {:?}",
+                            channel_id, error
+                        ),
+                    }
                 }
-                AudioCommands::Pause => {
-                    self.pause(channel_id);
+            }
+            AudioCommands::PlaySynced(plays) => {
+                let mut ids = vec![];
+                for play in plays.iter() {
+                    if let AudioCommands::Play(_, instance_settings) = play {
+                        ids.push(instance_settings.id);
+                    }
+                    self.run_command(play, channel_id.clone(), audio_sources, audio, commands);
                 }
-                AudioCommands::Resume => {
-                    self.resume(channel_id);
+                self.release_held_instances(&channel_id, &ids, None);
+            }
+        }
+    }
+
+    fn run_queued_command(
+        &mut self,
+        audio_command: &QueuedAudioCommand,
+        audio_sources: &Assets<AudioSource>,
+        audio: &Audio,
+        commands: &mut VecDeque<QueuedAudioCommand>,
+    ) {
+        match audio_command {
+            QueuedAudioCommand::Channel(audio_command, channel_id) => self.run_command(
+                audio_command,
+                channel_id.clone(),
+                audio_sources,
+                audio,
+                commands,
+            ),
+            QueuedAudioCommand::Global(audio_command) => {
+                self.run_global_command(audio_command, audio_sources, audio, commands)
+            }
+        }
+    }
+
+    /// Run a command that does not belong to a single channel
+    fn run_global_command(
+        &mut self,
+        audio_command: &GlobalAudioCommands,
+        audio_sources: &Assets<AudioSource>,
+        audio: &Audio,
+        commands: &mut VecDeque<QueuedAudioCommand>,
+    ) {
+        match audio_command {
+            #[cfg(feature = "output_device")]
+            GlobalAudioCommands::SetOutputDevice(device) => {
+                self.set_output_device(device.clone());
+            }
+            GlobalAudioCommands::StopAll => {
+                self.stop_all();
+            }
+            GlobalAudioCommands::StopAllWithFade(fade_tween) => {
+                self.stop_all_with_fade(*fade_tween);
+            }
+            GlobalAudioCommands::PauseAll => {
+                self.pause_all();
+            }
+            GlobalAudioCommands::ResumeAll => {
+                self.resume_all();
+            }
+            GlobalAudioCommands::ClearSolo => {
+                self.set_solo(None);
+            }
+            GlobalAudioCommands::Preload(audio_sources) => {
+                self.preloads.extend(audio_sources.iter().cloned());
+            }
+            GlobalAudioCommands::Unload(audio_source) => {
+                self.unload(audio_source);
+            }
+            GlobalAudioCommands::SetTrackVolume(track, volume) => {
+                self.set_track_volume(track.clone(), *volume as f64);
+            }
+            GlobalAudioCommands::SetMasterVolume(volume, tween) => {
+                self.set_master_volume(*volume as f64, *tween);
+            }
+            GlobalAudioCommands::SetReverb(track, settings) => {
+                self.set_reverb(track.clone(), *settings);
+            }
+            GlobalAudioCommands::RemoveReverb(track) => {
+                self.remove_reverb(track);
+            }
+            GlobalAudioCommands::AddSidechain(compressed, key, settings) => {
+                self.add_sidechain(compressed.clone(), key.clone(), *settings);
+            }
+            GlobalAudioCommands::RemoveSidechain(compressed) => {
+                self.remove_sidechain(compressed);
+            }
+            GlobalAudioCommands::SetMasterLimiter(settings) => {
+                self.set_master_limiter(*settings);
+            }
+            GlobalAudioCommands::RemoveMasterLimiter => {
+                self.remove_master_limiter();
+            }
+            GlobalAudioCommands::SetFilter(track, settings, tween) => {
+                self.set_filter(track.clone(), *settings, *tween);
+            }
+            GlobalAudioCommands::RemoveFilter(track) => {
+                self.remove_filter(track);
+            }
+            GlobalAudioCommands::SetPitchShift(track, semitones, tween) => {
+                self.set_pitch_shift(track.clone(), *semitones, *tween);
+            }
+            GlobalAudioCommands::RemovePitchShift(track) => {
+                self.remove_pitch_shift(track);
+            }
+            GlobalAudioCommands::Batch(batch) => {
+                // The commands of a batch were checked to be ready together
+                for audio_command in batch.iter() {
+                    self.run_queued_command(audio_command, audio_sources, audio, commands);
                 }
-                AudioCommands::SetVolume(volume) => {
-                    self.set_volume(channel_id, *volume as f64);
+            }
+        }
+    }
+
+    /// Drop all instances that stopped, either by finishing playing or by a stop command
+    ///
+    /// Stopped instances can never be resumed, but would otherwise accumulate
+    /// in long sessions and be iterated by every channel command.
+    ///
+    /// Returns an event for every instance that finished playing on its own.
+    fn prune_stopped_instances(&mut self) -> Vec<AudioFinished> {
+        let mut finished = vec![];
+        for (channel, instances) in self.instances.iter_mut() {
+            instances.retain(|instance| {
+                if instance.handle.state() != InstanceState::Stopped {
+                    return true;
                 }
-                AudioCommands::SetPanning(panning) => {
-                    self.set_panning(channel_id, *panning as f64);
+                if !instance.stopped_by_command && instance.loop_start.is_none() {
+                    finished.push(AudioFinished::new(instance.id, channel, instance.on_finish));
                 }
-                AudioCommands::SetPitch(pitch) => {
-                    self.set_pitch(channel_id, *pitch as f64);
+                false
+            });
+        }
+        self.instances.retain(|_, instances| !instances.is_empty());
+        if let Some(manager) = self.manager.as_mut() {
+            for (channel, streams) in self.streams.iter_mut() {
+                for (id, _) in streams.iter().filter(|(_, control)| control.is_finished()) {
+                    if let Err(error) = manager.remove_stream(*id) {
+                        warn!(
+                            "Failed to remove finished stream in channel {:?}: {:?}",
+                            channel, error
+                        );
+                    }
                 }
+                streams.retain(|(_, control)| !control.is_finished());
+            }
+        }
+        self.streams.retain(|_, streams| !streams.is_empty());
+        finished
+    }
+
+    fn start_metronome(&mut self, channel: AudioChannel, tempo: f64, intervals: &[f64]) {
+        self.stop_metronome(&channel);
+        let manager = match self.manager.as_mut() {
+            Some(manager) => manager,
+            None => return,
+        };
+        let mut emitted_intervals = intervals.to_vec();
+        if !emitted_intervals.contains(&SYNC_INTERVAL) {
+            emitted_intervals.push(SYNC_INTERVAL);
+        }
+        let settings = MetronomeSettings::new()
+            .tempo(Tempo(tempo))
+            .interval_events_to_emit(emitted_intervals)
+            // Leave room for the beats of several frames, in case the app stalls
+            .event_queue_capacity(100);
+        let mut handle = match manager.add_metronome(settings) {
+            Ok(handle) => handle,
+            Err(error) => {
+                warn!(
+                    "Failed to add a metronome for channel {:?}: {:?}",
+                    channel, error
+                );
+                return;
+            }
+        };
+        if let Err(error) = handle.start() {
+            warn!(
+                "Failed to start the metronome of channel {:?}: {:?}",
+                channel, error
+            );
+        }
+        self.metronomes
+            .insert(channel, ChannelMetronome::new(handle, intervals, tempo));
+    }
+
+    fn stop_metronome(&mut self, channel: &AudioChannel) {
+        let metronome = match self.metronomes.remove(channel) {
+            Some(metronome) => metronome,
+            None => return,
+        };
+        if let Some(manager) = self.manager.as_mut() {
+            if let Err(error) = manager.remove_metronome(metronome.handle.id()) {
+                warn!(
+                    "Failed to remove the metronome of channel {:?}: {:?}",
+                    channel, error
+                );
+            }
+        }
+    }
+
+    /// The beats that the metronomes reported since the last frame
+    fn collect_beats(&mut self) -> Vec<AudioBeat> {
+        let mut beats = vec![];
+        for (channel, metronome) in self.metronomes.iter_mut() {
+            beats.extend(metronome.beats(channel));
+        }
+        beats
+    }
+
+    fn update_metronome_clocks(&mut self, delta: f64) {
+        for metronome in self.metronomes.values_mut() {
+            metronome.advance(delta);
+        }
+    }
+
+    fn update_clock_state(&self, clock_state: &mut AudioClockState) {
+        clock_state.clocks.clear();
+        clock_state.clocks.extend(
+            self.metronomes
+                .iter()
+                .map(|(channel, metronome)| (channel.clone(), metronome.clock())),
+        );
+    }
+
+    fn update_cooldowns(&mut self, delta: f64) {
+        self.cooldowns.retain(|_, cooldown| {
+            *cooldown -= delta;
+            *cooldown > 0.0
+        });
+    }
+
+    fn update_instances(&mut self, delta: f64) {
+        for (channel, instances) in self.instances.iter_mut() {
+            let pitch = self
+                .channels
+                .get(channel)
+                .map_or(1.0, |channel_state| channel_state.pitch);
+            for instance in instances.iter_mut() {
+                instance.update_position(delta, pitch);
             }
-            i += 1;
+        }
+    }
+
+    fn update_state(&self, audio_state: &mut AudioState) {
+        audio_state.channels.clear();
+        audio_state.instances.clear();
+        audio_state.kira_instances.clear();
+        audio_state.master_level = self
+            .master_meter
+            .as_ref()
+            .map(|meter| meter.levels.level())
+            .unwrap_or_default();
+        audio_state.track_levels.clear();
+        audio_state.track_levels.extend(
+            self.meters
+                .iter()
+                .map(|(track, meter)| (track.clone(), meter.levels.level())),
+        );
+        for (channel, channel_state) in self.channels.iter() {
+            audio_state.channels.insert(
+                channel.clone(),
+                ChannelPlaybackState {
+                    volume: self.channel_volume(channel) as f32,
+                    panning: channel_state.panning as f32,
+                    pitch: channel_state.pitch as f32,
+                    ..Default::default()
+                },
+            );
+        }
+        for (channel, instances) in self.instances.iter() {
+            let playback_state = audio_state.channels.entry(channel.clone()).or_default();
+            playback_state.playing_instances = instances
+                .iter()
+                .filter(|instance| instance.handle.state() == InstanceState::Playing)
+                .count();
+            playback_state.active_instances = instances
+                .iter()
+                .filter(|instance| instance.handle.state() != InstanceState::Stopped)
+                .count();
+            playback_state.position = instances
+                .iter()
+                .rev()
+                .find(|instance| instance.handle.state() != InstanceState::Stopped)
+                .map(|instance| instance.position);
+            playback_state.state = PlaybackState::of_channel(
+                instances
+                    .iter()
+                    .map(|instance| PlaybackState::from_instance_state(instance.handle.state())),
+            );
+            audio_state
+                .instances
+                .extend(instances.iter().map(|instance| {
+                    (
+                        instance.id,
+                        PlaybackState::from_instance_state(instance.handle.state()),
+                    )
+                }));
+            audio_state.kira_instances.extend(
+                instances
+                    .iter()
+                    .map(|instance| (instance.id, instance.handle.clone())),
+            );
         }
     }
 }
 
 struct ChannelState {
     volume: f64,
+    volume_parameter: Option<ParameterHandle>,
+    /// Only created once the pitch is tweened
+    pitch_parameter: Option<ParameterHandle>,
+    /// Only created once the panning is tweened
+    panning_parameter: Option<ParameterHandle>,
+    duck_factor: f64,
+    muted: bool,
     pitch: f64,
+    /// Kira panning from 0 (left) to 1 (right); the default 0.5 is a centered balance of 0
     panning: f64,
+    /// Whether the channel was paused by the latest pause or resume command
+    paused: bool,
+    track: Option<AudioMixerTrack>,
+    max_voices: Option<(usize, VoiceStealing)>,
+}
+
+impl ChannelState {
+    /// The volume that is actually applied to the channel's instances
+    ///
+    /// While muted, this is zero but `volume` is kept to restore it when unmuting.
+    fn effective_volume(&self) -> f64 {
+        if self.muted {
+            return 0.0;
+        }
+        self.volume * self.duck_factor
+    }
 }
 
 impl Default for ChannelState {
     fn default() -> Self {
         ChannelState {
             volume: 1.0,
+            volume_parameter: None,
+            pitch_parameter: None,
+            panning_parameter: None,
+            duck_factor: 1.0,
+            muted: false,
             pitch: 1.0,
             panning: 0.5,
+            paused: false,
+            track: None,
+            max_voices: None,
+        }
+    }
+}
+
+/// The state of the fade out when the app exits, see [`AudioSettings::fade_out_on_exit`]
+enum ExitFade {
+    /// Waiting for the app to exit
+    Waiting,
+    /// Holding back the exit until the fade is done, with the remaining seconds
+    Fading(f64),
+    /// The exit was sent again after the fade
+    Done,
+}
+
+/// Hold back the exit of the app until the audio faded out, if configured
+///
+/// Only exits that are sent before the audio stage can be held back.
+pub fn fade_out_on_exit_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let fade_duration = match audio_output.settings.fade_out_on_exit {
+        Some(fade_duration) => fade_duration,
+        None => return,
+    };
+    let mut exit_events = match resources.get_mut::<Events<AppExit>>() {
+        Some(exit_events) => exit_events,
+        None => return,
+    };
+    let delta = audio_output
+        .audio_delta(resources.get::<Time>().map(|time| time.delta_seconds_f64()))
+        .unwrap_or(0.0);
+    match audio_output.exit_fade {
+        ExitFade::Waiting => {
+            let exit = audio_output.exit_reader.latest(&exit_events).cloned();
+            let playing = audio_output
+                .instances
+                .values()
+                .flatten()
+                .any(|instance| instance.handle.state() == InstanceState::Playing);
+            if exit.is_none() || !audio_output.is_available() || !playing {
+                return;
+            }
+            audio_output.stop_all_with_fade(FadeCurve::Linear.tween(fade_duration, false));
+            audio_output.exit_fade = ExitFade::Fading(fade_duration.as_secs_f64());
+            // The runner exits as soon as it reads an exit event
+            exit_events.clear();
+        }
+        ExitFade::Fading(remaining) if remaining > delta => {
+            audio_output.exit_fade = ExitFade::Fading(remaining - delta);
+            exit_events.clear();
+        }
+        ExitFade::Fading(_) => {
+            audio_output.exit_fade = ExitFade::Done;
+            exit_events.send(AppExit);
+        }
+        ExitFade::Done => (),
+    }
+}
+
+/// Create the audio manager once it was requested
+pub fn initialize_audio_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let audio = resources.get::<Audio>().unwrap();
+    if !audio_output.is_initialized() && audio.initialization.is_requested() {
+        audio_output.initialize();
+        audio.initialization.finish(audio_output.is_available());
+    }
+}
+
+/// Drop the instances that stopped and start the next audio source of their queues
+pub fn finish_stopped_audio_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let audio = resources.get::<Audio>().unwrap();
+    let finished = audio_output.prune_stopped_instances();
+    audio_output.advance_queues(&finished, &audio);
+    if let Some(mut events) = resources.get_mut::<Events<AudioFinished>>() {
+        for event in finished {
+            events.send(event);
         }
     }
 }
 
+/// Unload modified and removed audio sources
+pub fn audio_asset_events_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let audio = resources.get::<Audio>().unwrap();
+    if let (Some(asset_events), Some(audio_sources)) = (
+        resources.get::<Events<AssetEvent<AudioSource>>>(),
+        resources.get::<Assets<AudioSource>>(),
+    ) {
+        audio_output.handle_asset_events(&asset_events, &audio_sources, &audio);
+    }
+}
+
+/// Advance the instance positions, cooldowns, load timeouts and metronome clocks
+pub fn advance_audio_time_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let delta = resources.get::<Time>().map(|time| time.delta_seconds_f64());
+    if let Some(delta) = audio_output.audio_delta(delta) {
+        audio_output.update_instances(delta);
+        audio_output.update_spatial(delta);
+        audio_output.update_cooldowns(delta);
+        audio_output.update_load_waits(delta);
+        audio_output.update_metronome_clocks(delta);
+    }
+}
+
 pub fn play_queued_audio_system(_world: &mut World, resources: &mut Resources) {
     let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
     let mut audio = resources.get_mut::<Audio>().unwrap();
     if let Some(audio_sources) = resources.get::<Assets<AudioSource>>() {
-        audio_output.run_queued_audio_commands(&*audio_sources, &mut *audio);
+        audio_output.run_queued_audio_commands(&audio_sources, &mut audio);
+    }
+    #[cfg(feature = "output_device")]
+    {
+        audio_output.check_output_device();
+        if audio_output.is_initialized() {
+            audio.initialization.finish(audio_output.is_available());
+        }
+    }
+}
+
+/// Process the audio of the frame when running without an audio device
+#[cfg(feature = "headless")]
+pub fn process_headless_audio_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let delta = resources.get::<Time>().map(|time| time.delta_seconds_f64());
+    audio_output.process_headless(delta);
+}
+
+pub fn update_ducking_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    audio_output.update_ducking();
+}
+
+/// Send the audio events and update the audio state with the meters and metronomes
+pub fn update_audio_state_system(_world: &mut World, resources: &mut Resources) {
+    let mut audio_output = resources.get_thread_local_mut::<AudioOutput>().unwrap();
+    let audio = resources.get::<Audio>().unwrap();
+    if let Some(mut events) = resources.get_mut::<Events<AudioError>>() {
+        for error in audio_output.errors.drain(..) {
+            events.send(error);
+        }
+    }
+    if let Some(mut events) = resources.get_mut::<Events<AudioBeat>>() {
+        for beat in audio_output.collect_beats() {
+            events.send(beat);
+        }
+    }
+    if let Some(mut clock_state) = resources.get_mut::<AudioClockState>() {
+        audio_output.update_clock_state(&mut clock_state);
+    }
+    if let Some(mut audio_state) = resources.get_mut::<AudioState>() {
+        audio_output.update_state(&mut audio_state);
+        audio.update_instance_counts(&audio_state);
     }
 }
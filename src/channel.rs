@@ -1,7 +1,17 @@
+use bevy::app::AppBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::any::type_name;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
 /// A channel to play audio in
 ///
 /// You can play audio in this channel and controll
-/// properties like the volume, pitch or panning
+/// properties like the volume, pitch or panning.
+/// With the `serde` feature, channels are serialized as their key, so they can be
+/// defined in config files, for example together with an
+/// [`AudioMixerSnapshot`](crate::AudioMixerSnapshot) of their settings.
 /// ```edition2018
 /// # use bevy_kira_audio::{AudioChannel, Audio};
 /// # use bevy::prelude::*;
@@ -12,6 +22,7 @@
 /// }
 /// ```
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct AudioChannel {
     key: String,
 }
@@ -39,4 +50,88 @@ impl AudioChannel {
     pub fn new(key: String) -> Self {
         AudioChannel { key }
     }
+
+    /// The key that identifies this channel
+    ///
+    /// ```edition2018
+    /// # use bevy_kira_audio::AudioChannel;
+    /// let channel = AudioChannel::from("music");
+    /// assert_eq!(channel.key(), "music");
+    /// ```
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The internal channel of [`Audio::play_oneshot`](crate::Audio::play_oneshot)
+    pub(crate) fn oneshot() -> Self {
+        AudioChannel {
+            key: "oneshot_channel".to_string(),
+        }
+    }
+}
+
+impl From<String> for AudioChannel {
+    fn from(key: String) -> Self {
+        AudioChannel::new(key)
+    }
+}
+
+impl From<&str> for AudioChannel {
+    fn from(key: &str) -> Self {
+        AudioChannel::new(key.to_owned())
+    }
+}
+
+/// A channel that is identified by a marker type instead of a string
+///
+/// Register typed channels with [`AudioApp::add_audio_channel`]. Systems can then
+/// request exactly the channels they need as resources. Typed channels dereference
+/// to an [`AudioChannel`], so they work with all channel methods of [`Audio`](crate::Audio).
+/// ```edition2018
+/// # use bevy_kira_audio::{Audio, AudioApp, TypedAudioChannel};
+/// # use bevy::prelude::*;
+/// struct Music;
+///
+/// fn build(app: &mut AppBuilder) {
+///     app.add_audio_channel::<Music>();
+/// }
+///
+/// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>, music: Res<TypedAudioChannel<Music>>) {
+///     audio.play_looped_in_channel(asset_server.load("music.ogg"), &music);
+/// }
+/// ```
+pub struct TypedAudioChannel<T> {
+    channel: AudioChannel,
+    _marker: PhantomData<T>,
+}
+
+impl<T: 'static> Default for TypedAudioChannel<T> {
+    fn default() -> Self {
+        TypedAudioChannel {
+            channel: AudioChannel {
+                key: format!("typed_channel::{}", type_name::<T>()),
+            },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Deref for TypedAudioChannel<T> {
+    type Target = AudioChannel;
+
+    fn deref(&self) -> &AudioChannel {
+        &self.channel
+    }
+}
+
+/// Extension trait to register typed audio channels
+pub trait AudioApp {
+    /// Add a [`TypedAudioChannel`] resource for the marker type `T`
+    fn add_audio_channel<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl AudioApp for AppBuilder {
+    fn add_audio_channel<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.init_resource::<TypedAudioChannel<T>>()
+    }
 }
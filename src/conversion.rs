@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+/// Decibel values at or below this are treated as silence
+pub const SILENCE_DECIBELS: f32 = -80.0;
+
+/// The highest supported gain in decibels; louder values are clamped to this
+pub const MAX_DECIBELS: f32 = 24.0;
+
+/// Convert a volume in decibels to a linear amplitude
+///
+/// 0 dB is the original volume (amplitude 1). Values at or below
+/// [`SILENCE_DECIBELS`] are silent and values above [`MAX_DECIBELS`] are clamped.
+/// ```edition2018
+/// # use bevy_kira_audio::decibels_to_amplitude;
+/// assert_eq!(decibels_to_amplitude(0.0), 1.0);
+/// assert_eq!(decibels_to_amplitude(f32::NEG_INFINITY), 0.0);
+/// ```
+pub fn decibels_to_amplitude(decibels: f32) -> f32 {
+    if decibels.is_nan() || decibels <= SILENCE_DECIBELS {
+        return 0.0;
+    }
+    10f32.powf(decibels.min(MAX_DECIBELS) / 20.0)
+}
+
+/// Convert a linear amplitude to a volume in decibels
+///
+/// This is the inverse of [`decibels_to_amplitude`]. Silence is negative infinity.
+/// ```edition2018
+/// # use bevy_kira_audio::amplitude_to_decibels;
+/// assert_eq!(amplitude_to_decibels(1.0), 0.0);
+/// assert_eq!(amplitude_to_decibels(0.0), f32::NEG_INFINITY);
+/// ```
+pub fn amplitude_to_decibels(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    20.0 * amplitude.log10()
+}
+
+/// Convert a pitch offset in semitones to a pitch factor
+///
+/// 12 semitones are one octave, which doubles the pitch. Negative values lower the pitch.
+/// ```edition2018
+/// # use bevy_kira_audio::semitones_to_pitch;
+/// assert_eq!(semitones_to_pitch(0.0), 1.0);
+/// assert_eq!(semitones_to_pitch(12.0), 2.0);
+/// assert_eq!(semitones_to_pitch(-12.0), 0.5);
+/// ```
+pub fn semitones_to_pitch(semitones: f32) -> f32 {
+    2f32.powf(semitones / 12.0)
+}
+
+/// Convert a pitch factor to a pitch offset in semitones
+///
+/// This is the inverse of [`semitones_to_pitch`]. A pitch of zero is negative infinity.
+/// ```edition2018
+/// # use bevy_kira_audio::pitch_to_semitones;
+/// assert_eq!(pitch_to_semitones(1.0), 0.0);
+/// assert_eq!(pitch_to_semitones(2.0), 12.0);
+/// assert_eq!(pitch_to_semitones(0.5), -12.0);
+/// ```
+pub fn pitch_to_semitones(pitch: f32) -> f32 {
+    if pitch <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    12.0 * pitch.log2()
+}
+
+/// Convert a stereo balance to a Kira panning value
+///
+/// A balance of -1 is fully left, 0 is centered and 1 is fully right.
+/// Kira uses panning values from 0 (left) to 1 (right) with 0.5 as the center.
+/// Values outside of the balance range are clamped.
+/// ```edition2018
+/// # use bevy_kira_audio::balance_to_panning;
+/// assert_eq!(balance_to_panning(0.0), 0.5);
+/// assert_eq!(balance_to_panning(-1.0), 0.0);
+/// assert_eq!(balance_to_panning(1.0), 1.0);
+/// ```
+pub fn balance_to_panning(balance: f32) -> f32 {
+    (balance.clamp(-1.0, 1.0) + 1.0) / 2.0
+}
+
+/// Convert a Kira panning value to a stereo balance
+///
+/// This is the inverse of [`balance_to_panning`].
+/// ```edition2018
+/// # use bevy_kira_audio::panning_to_balance;
+/// assert_eq!(panning_to_balance(0.5), 0.0);
+/// assert_eq!(panning_to_balance(0.0), -1.0);
+/// assert_eq!(panning_to_balance(1.0), 1.0);
+/// ```
+pub fn panning_to_balance(panning: f32) -> f32 {
+    panning.clamp(0.0, 1.0) * 2.0 - 1.0
+}
+
+/// A position or length in audio, given in seconds or as a [`Duration`]
+///
+/// Methods that take a time, like [`Audio::seek_to`](crate::Audio::seek_to), accept
+/// either. Kira works with seconds, so durations are converted internally.
+/// ```edition2018
+/// # use bevy_kira_audio::IntoSeconds;
+/// # use std::time::Duration;
+/// assert_eq!(1.5.into_seconds(), 1.5);
+/// assert_eq!(Duration::from_millis(1500).into_seconds(), 1.5);
+/// ```
+pub trait IntoSeconds {
+    /// The time in seconds
+    fn into_seconds(self) -> f64;
+}
+
+impl IntoSeconds for f64 {
+    fn into_seconds(self) -> f64 {
+        self
+    }
+}
+
+impl IntoSeconds for Duration {
+    fn into_seconds(self) -> f64 {
+        self.as_secs_f64()
+    }
+}
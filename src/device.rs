@@ -0,0 +1,126 @@
+use bevy::prelude::warn;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, SampleRate, StreamConfig};
+use kira::manager::{AudioManager, AudioManagerSettings};
+use kira::Frame;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Kira's backend runs at this sample rate when it is driven by the plugin
+const BACKEND_SAMPLE_RATE: u32 = 48000;
+
+/// The names of all available audio output devices
+pub(crate) fn output_device_names() -> Vec<String> {
+    match cpal::default_host().output_devices() {
+        Ok(devices) => devices.filter_map(|device| device.name().ok()).collect(),
+        Err(error) => {
+            warn!("Failed to list the audio output devices: {}", error);
+            vec![]
+        }
+    }
+}
+
+/// The stream that plays the audio of a Kira backend on an output device
+pub(crate) struct DeviceOutput {
+    /// The audio plays as long as the stream exists
+    _stream: cpal::Stream,
+    /// Set when the device fails, for example because it was disconnected
+    lost: Arc<AtomicBool>,
+}
+
+impl DeviceOutput {
+    /// Create an audio manager that plays on the output device with the given name
+    ///
+    /// Without a name, the default output device is used.
+    pub(crate) fn create(
+        device_name: Option<&str>,
+        settings: AudioManagerSettings,
+    ) -> Result<(AudioManager, DeviceOutput), String> {
+        let device = Self::find_device(device_name)?;
+        let config = Self::stream_config(&device)?;
+        let channels = config.channels as usize;
+        // Devices that do not support the backend's sample rate get linearly interpolated frames
+        let step = BACKEND_SAMPLE_RATE as f64 / config.sample_rate.0 as f64;
+        let (manager, mut backend) = AudioManager::new_without_audio_thread(settings);
+        let mut previous = Frame::from_mono(0.0);
+        let mut next = Frame::from_mono(0.0);
+        let mut time = 0.0;
+        let lost = Arc::new(AtomicBool::new(false));
+        let error_flag = lost.clone();
+        let stream = device
+            .build_output_stream(
+                &config,
+                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                    for frame in data.chunks_exact_mut(channels) {
+                        time += step;
+                        while time >= 1.0 {
+                            time -= 1.0;
+                            previous = next;
+                            next = backend.process();
+                        }
+                        let out = previous + (next - previous) * time as f32;
+                        if channels == 1 {
+                            frame[0] = (out.left + out.right) / 2.0;
+                        } else {
+                            frame[0] = out.left;
+                            frame[1] = out.right;
+                        }
+                    }
+                },
+                move |error| {
+                    warn!("Audio output device failed: {}", error);
+                    error_flag.store(true, Ordering::Relaxed);
+                },
+            )
+            .map_err(|error| error.to_string())?;
+        stream.play().map_err(|error| error.to_string())?;
+        Ok((
+            manager,
+            DeviceOutput {
+                _stream: stream,
+                lost,
+            },
+        ))
+    }
+
+    /// Check if the device failed and stopped playing
+    pub(crate) fn is_lost(&self) -> bool {
+        self.lost.load(Ordering::Relaxed)
+    }
+
+    fn find_device(device_name: Option<&str>) -> Result<Device, String> {
+        let host = cpal::default_host();
+        match device_name {
+            None => host
+                .default_output_device()
+                .ok_or_else(|| "No default output device".to_owned()),
+            Some(name) => host
+                .output_devices()
+                .map_err(|error| error.to_string())?
+                .find(|device| device.name().ok().as_deref() == Some(name))
+                .ok_or_else(|| format!("No output device named {:?}", name)),
+        }
+    }
+
+    /// Prefer a configuration with the backend's sample rate to avoid interpolating
+    fn stream_config(device: &Device) -> Result<StreamConfig, String> {
+        let sample_rate = SampleRate(BACKEND_SAMPLE_RATE);
+        let matching = device
+            .supported_output_configs()
+            .ok()
+            .and_then(|mut configs| {
+                configs.find(|config| {
+                    config.sample_format() == cpal::SampleFormat::F32
+                        && config.min_sample_rate() <= sample_rate
+                        && config.max_sample_rate() >= sample_rate
+                })
+            });
+        match matching {
+            Some(config) => Ok(config.with_sample_rate(sample_rate).config()),
+            None => device
+                .default_output_config()
+                .map(|config| config.config())
+                .map_err(|error| error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::channel::AudioChannel;
+use crate::source::AudioSource;
+use bevy::prelude::Handle;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Event that is sent when the audio backend fails to apply a command
+///
+/// The methods of [`Audio`](crate::Audio) only queue commands, so their failures
+/// are reported later through this event. All errors are logged as well.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::AudioError;
+///
+/// #[derive(Default)]
+/// struct ErrorReader {
+///     reader: EventReader<AudioError>,
+/// }
+///
+/// fn my_system(mut state: Local<ErrorReader>, events: Res<Events<AudioError>>) {
+///     for error in state.reader.iter(&events) {
+///         info!("Audio failed: {}", error);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub enum AudioError {
+    /// Kira failed to add the audio source, for example because the sound limit is reached
+    AddSound {
+        /// The audio source that could not be added
+        audio_source: Handle<AudioSource>,
+        /// The error reported by Kira
+        message: String,
+    },
+    /// Kira failed to add the arrangement that plays the audio source
+    AddArrangement {
+        /// The audio source that was supposed to be played
+        audio_source: Handle<AudioSource>,
+        /// The error reported by Kira
+        message: String,
+    },
+    /// Kira failed to play an instance, for example because the instance limit is reached
+    Play {
+        /// The channel the instance was supposed to play in
+        channel: AudioChannel,
+        /// The error reported by Kira
+        message: String,
+    },
+    /// A file could not be streamed
+    Stream {
+        /// The channel the file was supposed to be streamed in
+        channel: AudioChannel,
+        /// The streamed file
+        path: PathBuf,
+        /// The reason the stream failed
+        message: String,
+    },
+    /// A command waited too long for its audio source to load and was dropped
+    ///
+    /// See [`AudioSettings::load_timeout`](crate::AudioSettings::load_timeout).
+    LoadTimeout {
+        /// The audio source that did not load
+        audio_source: Handle<AudioSource>,
+        /// The channel of the dropped command, or `None` for a dropped batch of commands
+        channel: Option<AudioChannel>,
+        /// How long the command waited
+        timeout: Duration,
+    },
+    /// An audio output device could not be used or stopped working
+    ///
+    /// The audio plays on the default output device instead, if there is one.
+    OutputDevice {
+        /// The name of the device, or `None` for the default output device
+        device: Option<String>,
+        /// The reason the device failed
+        message: String,
+    },
+}
+
+impl fmt::Display for AudioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AudioError::AddSound {
+                audio_source,
+                message,
+            } => write!(f, "Failed to add sound {:?}: {}", audio_source, message),
+            AudioError::AddArrangement {
+                audio_source,
+                message,
+            } => write!(
+                f,
+                "Failed to add arrangement for {:?}: {}",
+                audio_source, message
+            ),
+            AudioError::Play { channel, message } => write!(
+                f,
+                "Failed to play arrangement in channel {:?}: {}",
+                channel, message
+            ),
+            AudioError::Stream {
+                channel,
+                path,
+                message,
+            } => write!(
+                f,
+                "Failed to stream {:?} in channel {:?}: {}",
+                path, channel, message
+            ),
+            AudioError::LoadTimeout {
+                audio_source,
+                channel,
+                timeout,
+            } => {
+                match channel {
+                    Some(channel) => {
+                        write!(f, "Dropped an audio command in channel {:?}", channel)?
+                    }
+                    None => write!(f, "Dropped a batch of audio commands")?,
+                }
+                write!(
+                    f,
+                    ", because the audio source {:?} did not load within {} seconds",
+                    audio_source,
+                    timeout.as_secs_f64()
+                )
+            }
+            AudioError::OutputDevice {
+                device: Some(device),
+                message,
+            } => write!(f, "Failed to use output device {:?}: {}", device, message),
+            AudioError::OutputDevice {
+                device: None,
+                message,
+            } => write!(f, "Failed to use the default output device: {}", message),
+        }
+    }
+}
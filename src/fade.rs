@@ -0,0 +1,48 @@
+use kira::parameter::tween::{EaseDirection, Easing, Tween};
+use std::time::Duration;
+
+/// The shape of a fade or volume transition
+///
+/// Panning and pitch transitions use the same curves, with the higher value in place
+/// of the louder end.
+///
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, FadeCurve};
+/// # use std::time::Duration;
+///
+/// fn my_system(audio: Res<Audio>) {
+///     audio.stop_with_fade_curve(Duration::from_secs(2), FadeCurve::Logarithmic);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FadeCurve {
+    /// The volume changes at a constant rate
+    Linear,
+    /// The volume stays close to its louder end for longer
+    ///
+    /// Crossfading two sounds with this curve keeps the total loudness roughly constant.
+    EqualPower,
+    /// The volume stays close to its quieter end for longer
+    ///
+    /// This is perceived as a steady change of loudness, which suits long music fades.
+    Logarithmic,
+}
+
+impl FadeCurve {
+    /// Create a Kira tween for a transition that raises or lowers the volume
+    pub(crate) fn tween(&self, duration: Duration, rising: bool) -> Tween {
+        let (easing, ease_direction) = match self {
+            FadeCurve::Linear => (Easing::Linear, EaseDirection::In),
+            FadeCurve::EqualPower if rising => (Easing::PowI(2), EaseDirection::Out),
+            FadeCurve::EqualPower => (Easing::PowI(2), EaseDirection::In),
+            FadeCurve::Logarithmic if rising => (Easing::PowI(3), EaseDirection::In),
+            FadeCurve::Logarithmic => (Easing::PowI(3), EaseDirection::Out),
+        };
+        Tween {
+            duration: duration.as_secs_f64(),
+            easing,
+            ease_direction,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/// The frequencies that the filter of a mixer track removes
+///
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioMixerTrack, FilterMode};
+///
+/// fn dive_underwater(audio: Res<Audio>) {
+///     audio.set_filter(
+///         &AudioMixerTrack::new("environment".to_owned()),
+///         FilterMode::LowPass,
+///         800.0,
+///         0.2,
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Removes frequencies above the cutoff frequency
+    LowPass,
+    /// Removes frequencies below the cutoff frequency
+    HighPass,
+    /// Removes frequencies above and below the cutoff frequency
+    BandPass,
+    /// Removes frequencies around the cutoff frequency
+    Notch,
+}
+
+impl FilterMode {
+    /// A cutoff frequency at which the filter barely changes the audio
+    ///
+    /// Tweened filters that are newly added start at this cutoff.
+    pub(crate) fn open_cutoff(&self) -> f64 {
+        match self {
+            FilterMode::LowPass | FilterMode::BandPass => 20000.0,
+            FilterMode::HighPass | FilterMode::Notch => 20.0,
+        }
+    }
+}
+
+impl From<FilterMode> for kira::mixer::effect::filter::FilterMode {
+    fn from(mode: FilterMode) -> Self {
+        match mode {
+            FilterMode::LowPass => kira::mixer::effect::filter::FilterMode::LowPass,
+            FilterMode::HighPass => kira::mixer::effect::filter::FilterMode::HighPass,
+            FilterMode::BandPass => kira::mixer::effect::filter::FilterMode::BandPass,
+            FilterMode::Notch => kira::mixer::effect::filter::FilterMode::Notch,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::{CachedValue, Frame, Value};
+
+/// Scales the audio of a mixer track by a volume parameter
+///
+/// Kira can not change the volume of a track after creating it, so the master volume
+/// is applied by this effect on the master track.
+#[derive(Debug)]
+pub(crate) struct Gain {
+    volume: CachedValue<f64>,
+}
+
+impl Gain {
+    pub(crate) fn new(volume: Value<f64>) -> Self {
+        Gain {
+            volume: CachedValue::new(volume, 1.0),
+        }
+    }
+}
+
+impl Effect for Gain {
+    fn process(&mut self, _dt: f64, input: Frame, parameters: &Parameters) -> Frame {
+        self.volume.update(parameters);
+        input * self.volume.value() as f32
+    }
+}
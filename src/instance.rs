@@ -0,0 +1,99 @@
+use crate::channel::AudioChannel;
+use std::hash::{Hash, Hasher};
+
+/// Handle to a single instance of playing audio
+///
+/// All play methods of [`Audio`](crate::Audio) return a handle to the instance they start.
+/// Use it to control that instance independently of the rest of its channel.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::Audio;
+///
+/// fn my_system(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+///     let instance = audio.play(asset_server.load("audio.mp3"));
+///     audio.set_instance_volume(0.5, &instance);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct InstanceHandle {
+    pub(crate) id: u64,
+    pub(crate) channel: AudioChannel,
+    pub(crate) name: Option<String>,
+}
+
+impl InstanceHandle {
+    pub(crate) fn new(id: u64, channel: &AudioChannel) -> Self {
+        InstanceHandle {
+            id,
+            channel: channel.clone(),
+            name: None,
+        }
+    }
+
+    /// The channel this instance is playing in
+    pub fn channel(&self) -> &AudioChannel {
+        &self.channel
+    }
+
+    /// The name the instance was played with
+    ///
+    /// See [`Audio::play_named`](crate::Audio::play_named).
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+// Handles are compared without their name, so that an instance
+// equals the handles of it that are sent in events
+impl PartialEq for InstanceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.channel == other.channel
+    }
+}
+
+impl Eq for InstanceHandle {}
+
+impl Hash for InstanceHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.channel.hash(state);
+    }
+}
+
+/// Event that is sent when an instance finished playing
+///
+/// Instances that are stopped by a command or that are looping do not send this event.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::AudioFinished;
+///
+/// #[derive(Default)]
+/// struct FinishedReader {
+///     reader: EventReader<AudioFinished>,
+/// }
+///
+/// fn my_system(mut state: Local<FinishedReader>, events: Res<Events<AudioFinished>>) {
+///     for event in state.reader.iter(&events) {
+///         info!("Audio finished in channel {:?}", event.channel);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AudioFinished {
+    /// The channel the instance was playing in
+    pub channel: AudioChannel,
+    /// The instance that finished playing
+    pub instance: InstanceHandle,
+    /// The id given to [`Audio::play_with_on_finish`](crate::Audio::play_with_on_finish)
+    pub on_finish: Option<u64>,
+}
+
+impl AudioFinished {
+    pub(crate) fn new(id: u64, channel: &AudioChannel, on_finish: Option<u64>) -> Self {
+        AudioFinished {
+            channel: channel.clone(),
+            instance: InstanceHandle::new(id, channel),
+            on_finish,
+        }
+    }
+}
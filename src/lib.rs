@@ -28,23 +28,118 @@
 use bevy::prelude::*;
 
 pub use audio::Audio;
-pub use source::AudioSource;
+pub use conversion::{
+    amplitude_to_decibels, balance_to_panning, decibels_to_amplitude, panning_to_balance,
+    pitch_to_semitones, semitones_to_pitch, IntoSeconds, MAX_DECIBELS, SILENCE_DECIBELS,
+};
+pub use error::AudioError;
+pub use fade::FadeCurve;
+pub use filter::FilterMode;
+pub use instance::{AudioFinished, InstanceHandle};
+pub use kira::sound::SoundSettings;
+pub use kira::Frame;
+pub use limiter::LimiterSettings;
+pub use meter::AudioLevel;
+pub use metronome::{AudioBeat, AudioClockState, MetronomeClock};
+pub use mixer::AudioMixerTrack;
+pub use play_settings::PlaySettings;
+pub use reverb::ReverbSettings;
+pub use settings::AudioSettings;
+pub use sidechain::SidechainSettings;
+pub use snapshot::{AudioMixerSnapshot, ChannelMixerSettings};
+pub use source::{
+    AudioFormat, AudioLoadSettings, AudioSource, ResampleSettings, ResamplingQuality,
+};
+pub use spatial::{AudioEmitter, AudioListener, ListenerMixing, Rolloff};
+pub use state::{AudioState, PlaybackState};
+pub use voices::VoiceStealing;
 
 mod audio;
 mod audio_output;
 mod channel;
+mod conversion;
+#[cfg(feature = "output_device")]
+mod device;
+mod error;
+mod fade;
+mod filter;
+mod gain;
+mod instance;
+mod limiter;
+mod meter;
+mod metronome;
+mod mixer;
+mod pitch_shift;
+mod play_settings;
+mod reverb;
+mod settings;
+mod sidechain;
+mod snapshot;
 mod source;
+mod spatial;
+mod state;
+mod stream;
+mod voices;
 
-use crate::audio_output::{play_queued_audio_system, AudioOutput};
+use crate::audio::AudioInitialization;
+#[cfg(feature = "headless")]
+use crate::audio_output::process_headless_audio_system;
+use crate::audio_output::{
+    advance_audio_time_system, audio_asset_events_system, fade_out_on_exit_system,
+    finish_stopped_audio_system, initialize_audio_system, play_queued_audio_system,
+    update_audio_state_system, update_ducking_system, AudioOutput,
+};
+use crate::spatial::update_spatial_audio_system;
+use std::sync::Arc;
 
-pub use channel::AudioChannel;
+pub use channel::{AudioApp, AudioChannel, TypedAudioChannel};
 
+/// The stages added by the [`AudioPlugin`]
+pub mod stage {
+    /// Stage in which the queued audio commands run, after `POST_UPDATE`
+    ///
+    /// Systems that use [`Audio`](crate::Audio) in an earlier stage have their commands
+    /// run in the same frame. The [`AudioState`](crate::AudioState) and the events of the
+    /// plugin, like [`AudioFinished`](crate::AudioFinished), are updated for systems in
+    /// later stages.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioFinished, AudioPlugin};
+    /// fn main() {
+    ///     App::build()
+    ///         .add_plugins(DefaultPlugins)
+    ///         .add_plugin(AudioPlugin)
+    ///         .add_stage_after(
+    ///             bevy_kira_audio::stage::AUDIO,
+    ///             "after_audio",
+    ///             SystemStage::parallel(),
+    ///         )
+    ///         .add_system_to_stage("after_audio", react_to_audio.system())
+    ///         .run();
+    /// }
+    ///
+    /// fn react_to_audio(
+    ///     mut reader: Local<EventReader<AudioFinished>>,
+    ///     events: Res<Events<AudioFinished>>,
+    /// ) {
+    ///     for _finished in reader.iter(&events) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub const AUDIO: &str = "audio";
+}
+
+#[cfg(feature = "aac")]
+use crate::source::AacLoader;
 #[cfg(feature = "flac")]
 use crate::source::FlacLoader;
 #[cfg(feature = "mp3")]
 use crate::source::Mp3Loader;
 #[cfg(feature = "ogg")]
 use crate::source::OggLoader;
+#[cfg(feature = "opus")]
+use crate::source::OpusLoader;
 #[cfg(feature = "wav")]
 use crate::source::WavLoader;
 
@@ -73,19 +168,80 @@ pub struct AudioPlugin;
 
 impl Plugin for AudioPlugin {
     fn build(&self, app: &mut AppBuilder) {
-        app.init_thread_local_resource::<AudioOutput>()
+        let settings = app
+            .resources()
+            .get::<AudioSettings>()
+            .map(|settings| (*settings).clone())
+            .unwrap_or_default();
+        let load_settings = app
+            .resources()
+            .get::<AudioLoadSettings>()
+            .map(|load_settings| (*load_settings).clone())
+            .unwrap_or_default();
+        let audio_output = AudioOutput::new(&settings);
+        let initialization =
+            AudioInitialization::new(audio_output.is_initialized(), audio_output.is_available());
+        app.add_thread_local_resource(audio_output)
             .add_asset::<AudioSource>();
 
         #[cfg(feature = "mp3")]
-        app.init_asset_loader::<Mp3Loader>();
+        app.add_asset_loader(Mp3Loader {
+            extensions: settings.loader_extensions(AudioFormat::Mp3),
+            load_settings: load_settings.clone(),
+        });
         #[cfg(feature = "ogg")]
-        app.init_asset_loader::<OggLoader>();
+        app.add_asset_loader(OggLoader {
+            extensions: settings.loader_extensions(AudioFormat::Ogg),
+            load_settings: load_settings.clone(),
+        });
         #[cfg(feature = "wav")]
-        app.init_asset_loader::<WavLoader>();
+        app.add_asset_loader(WavLoader {
+            extensions: settings.loader_extensions(AudioFormat::Wav),
+            load_settings: load_settings.clone(),
+        });
         #[cfg(feature = "flac")]
-        app.init_asset_loader::<FlacLoader>();
+        app.add_asset_loader(FlacLoader {
+            extensions: settings.loader_extensions(AudioFormat::Flac),
+            load_settings: load_settings.clone(),
+        });
+        #[cfg(feature = "aac")]
+        app.add_asset_loader(AacLoader {
+            extensions: settings.loader_extensions(AudioFormat::Aac),
+            load_settings: load_settings.clone(),
+        });
+        #[cfg(feature = "opus")]
+        app.add_asset_loader(OpusLoader {
+            extensions: settings.loader_extensions(AudioFormat::Opus),
+            load_settings: load_settings.clone(),
+        });
 
-        app.init_resource::<Audio>()
-            .add_system_to_stage(stage::POST_UPDATE, play_queued_audio_system.system());
+        app.add_resource(load_settings)
+            .add_resource(settings.listener_mixing)
+            .add_resource(Audio {
+                initialization: Arc::new(initialization),
+                ..Default::default()
+            })
+            .init_resource::<AudioState>()
+            .init_resource::<AudioClockState>()
+            .add_event::<AudioFinished>()
+            .add_event::<AudioError>()
+            .add_event::<AudioBeat>()
+            .add_stage_after(
+                bevy::app::stage::POST_UPDATE,
+                stage::AUDIO,
+                SystemStage::parallel(),
+            )
+            // The systems use the thread local audio output, so they run in this order
+            .add_system_to_stage(stage::AUDIO, update_spatial_audio_system.system())
+            .add_system_to_stage(stage::AUDIO, initialize_audio_system.system())
+            .add_system_to_stage(stage::AUDIO, finish_stopped_audio_system.system())
+            .add_system_to_stage(stage::AUDIO, audio_asset_events_system.system())
+            .add_system_to_stage(stage::AUDIO, advance_audio_time_system.system())
+            .add_system_to_stage(stage::AUDIO, play_queued_audio_system.system());
+        #[cfg(feature = "headless")]
+        app.add_system_to_stage(stage::AUDIO, process_headless_audio_system.system());
+        app.add_system_to_stage(stage::AUDIO, update_ducking_system.system())
+            .add_system_to_stage(stage::AUDIO, update_audio_state_system.system())
+            .add_system_to_stage(stage::AUDIO, fade_out_on_exit_system.system());
     }
 }
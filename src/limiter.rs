@@ -0,0 +1,78 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::{CachedValue, Frame, Value};
+use std::time::Duration;
+
+/// Settings for the limiter on the master output
+///
+/// The limiter lowers the volume of the whole output as soon as it would exceed the
+/// threshold, which keeps stacked loud sounds from clipping.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{decibels_to_amplitude, Audio, LimiterSettings};
+/// # use std::time::Duration;
+///
+/// fn protect_output(audio: Res<Audio>) {
+///     audio.set_master_limiter(LimiterSettings {
+///         threshold: decibels_to_amplitude(-1.0),
+///         release: Duration::from_millis(200),
+///     });
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LimiterSettings {
+    /// The highest amplitude of the output, from 0 to 1
+    pub threshold: f32,
+    /// How long the volume takes to recover after a peak
+    pub release: Duration,
+}
+
+impl Default for LimiterSettings {
+    fn default() -> Self {
+        LimiterSettings {
+            threshold: 0.9,
+            release: Duration::from_millis(100),
+        }
+    }
+}
+
+/// A peak limiter with an instant attack
+///
+/// The gain follows the loudest sample of both channels, so the output never exceeds
+/// the threshold. After a peak, the gain recovers exponentially over the release time.
+#[derive(Debug)]
+pub(crate) struct Limiter {
+    threshold: CachedValue<f64>,
+    release: CachedValue<f64>,
+    envelope: f64,
+}
+
+impl Limiter {
+    pub(crate) fn new(threshold: Value<f64>, release: Value<f64>) -> Self {
+        let defaults = LimiterSettings::default();
+        Limiter {
+            threshold: CachedValue::new(threshold, defaults.threshold as f64),
+            release: CachedValue::new(release, defaults.release.as_secs_f64()),
+            envelope: 0.0,
+        }
+    }
+}
+
+impl Effect for Limiter {
+    fn process(&mut self, dt: f64, input: Frame, parameters: &Parameters) -> Frame {
+        self.threshold.update(parameters);
+        self.release.update(parameters);
+        let peak = input.left.abs().max(input.right.abs()) as f64;
+        if peak >= self.envelope {
+            self.envelope = peak;
+        } else {
+            let release = self.release.value().max(dt);
+            self.envelope = peak + (self.envelope - peak) * (-dt / release).exp();
+        }
+        let threshold = self.threshold.value().clamp(0.0, 1.0);
+        if self.envelope <= threshold {
+            return input;
+        }
+        input * (threshold / self.envelope) as f32
+    }
+}
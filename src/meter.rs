@@ -0,0 +1,103 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::Frame;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// How long the meters take to follow a change of the level, in seconds
+///
+/// Close to the integration time of a classic VU meter.
+const METER_TIME: f64 = 0.3;
+
+/// The recent level of the audio on a mixer track or the master output
+///
+/// Read it from the [`AudioState`](crate::AudioState). The levels are amplitudes from 0
+/// to 1 and above, if the audio clips. Use
+/// [`amplitude_to_decibels`](crate::amplitude_to_decibels) to display them in decibels.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{amplitude_to_decibels, AudioState};
+///
+/// fn master_meter(audio_state: Res<AudioState>) {
+///     let level = audio_state.master_level();
+///     info!(
+///         "Peak {:.1} dB, RMS {:.1} dB",
+///         amplitude_to_decibels(level.peak),
+///         amplitude_to_decibels(level.rms)
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AudioLevel {
+    /// The highest sample of both stereo channels
+    ///
+    /// The peak rises instantly and falls off over about 300 milliseconds.
+    pub peak: f32,
+    /// The root mean square of both stereo channels, averaged over about 300 milliseconds
+    ///
+    /// This follows the perceived loudness more closely than the peak.
+    pub rms: f32,
+}
+
+/// The level measured by a meter, shared with the audio system
+#[derive(Debug, Default)]
+pub(crate) struct MeterLevels {
+    peak: AtomicU32,
+    rms: AtomicU32,
+}
+
+impl MeterLevels {
+    pub(crate) fn level(&self) -> AudioLevel {
+        AudioLevel {
+            peak: f32::from_bits(self.peak.load(Ordering::Relaxed)),
+            rms: f32::from_bits(self.rms.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Measures the level of a mixer track, without changing its audio
+#[derive(Debug)]
+pub(crate) struct Meter {
+    levels: Arc<MeterLevels>,
+    peak: f64,
+    mean_square: f64,
+    /// The time step that `decay` was calculated for
+    dt: f64,
+    /// How much of the previous level remains after one time step
+    decay: f64,
+}
+
+impl Meter {
+    /// Create a meter that continues from the current levels
+    pub(crate) fn new(levels: Arc<MeterLevels>) -> Self {
+        let level = levels.level();
+        Meter {
+            levels,
+            peak: level.peak as f64,
+            mean_square: (level.rms as f64).powi(2),
+            dt: 0.0,
+            decay: 0.0,
+        }
+    }
+}
+
+impl Effect for Meter {
+    fn process(&mut self, dt: f64, input: Frame, _parameters: &Parameters) -> Frame {
+        if (dt - self.dt).abs() > f64::EPSILON {
+            self.dt = dt;
+            self.decay = (-dt / METER_TIME).exp();
+        }
+        let peak = input.left.abs().max(input.right.abs()) as f64;
+        self.peak = peak.max(self.peak * self.decay);
+        let square = (input.left as f64).powi(2) / 2.0 + (input.right as f64).powi(2) / 2.0;
+        self.mean_square = square + (self.mean_square - square) * self.decay;
+        self.levels
+            .peak
+            .store((self.peak as f32).to_bits(), Ordering::Relaxed);
+        self.levels.rms.store(
+            (self.mean_square.sqrt() as f32).to_bits(),
+            Ordering::Relaxed,
+        );
+        input
+    }
+}
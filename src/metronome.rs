@@ -0,0 +1,168 @@
+use crate::channel::AudioChannel;
+use kira::metronome::handle::MetronomeHandle;
+use std::collections::HashMap;
+
+/// The interval in beats that every metronome reports to keep its clock in sync
+pub(crate) const SYNC_INTERVAL: f64 = 1.0;
+
+/// Event that is sent when a beat interval of a channel's metronome passed
+///
+/// Metronomes are started with [`Audio::start_metronome`](crate::Audio::start_metronome).
+/// They are advanced by the audio backend, so the beats stay in sync with the audio
+/// that is playing, independent of the frame rate. The events are sent in the frame
+/// after the audio backend reported the beat.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::AudioBeat;
+///
+/// #[derive(Default)]
+/// struct BeatReader {
+///     reader: EventReader<AudioBeat>,
+/// }
+///
+/// fn my_system(mut state: Local<BeatReader>, events: Res<Events<AudioBeat>>) {
+///     for event in state.reader.iter(&events) {
+///         if event.interval == 4.0 {
+///             info!("Bar {} starts", event.beat);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AudioBeat {
+    /// The channel of the metronome
+    pub channel: AudioChannel,
+    /// The interval in beats that passed
+    pub interval: f64,
+    /// How often the interval passed before since the metronome started
+    ///
+    /// The event at the start of the metronome has the count 0.
+    pub beat: u64,
+}
+
+/// Bevy resource with the musical position of the metronome in each channel
+///
+/// The plugin updates this resource once per frame. Unlike [`AudioBeat`] events, the
+/// position changes continuously between beats, which suits beat-synced animations.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{AudioChannel, AudioClockState};
+///
+/// fn pulse_on_beat(clock_state: Res<AudioClockState>, mut query: Query<&mut Transform>) {
+///     if let Some(clock) = clock_state.clock(&AudioChannel::default()) {
+///         let scale = 1.0 + 0.2 * (1.0 - clock.phase() as f32);
+///         for mut transform in query.iter_mut() {
+///             transform.scale = Vec3::splat(scale);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct AudioClockState {
+    pub(crate) clocks: HashMap<AudioChannel, MetronomeClock>,
+}
+
+impl AudioClockState {
+    /// Get the position of the metronome in the given channel
+    ///
+    /// Channels without a running metronome have no clock.
+    pub fn clock(&self, channel: &AudioChannel) -> Option<MetronomeClock> {
+        self.clocks.get(channel).copied()
+    }
+}
+
+/// The musical position of a metronome
+///
+/// The position follows the beats that the audio backend reports. Between them, it
+/// advances with the frame time, but never past the next beat that was not reported yet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetronomeClock {
+    /// The beats that passed since the metronome started, including the current fraction
+    pub beats: f64,
+    /// The tempo of the metronome in beats per minute
+    pub tempo: f64,
+}
+
+impl MetronomeClock {
+    /// The number of the current beat, counting from 0
+    pub fn beat(&self) -> u64 {
+        self.beats.floor() as u64
+    }
+
+    /// How far the current beat is along, from 0 at the beat to 1 at the next one
+    pub fn phase(&self) -> f64 {
+        self.beats.fract()
+    }
+
+    /// The number of the current bar with the given number of beats, counting from 0
+    pub fn bar(&self, beats_per_bar: u64) -> u64 {
+        self.beat() / beats_per_bar.max(1)
+    }
+
+    /// How far the current bar with the given number of beats is along, from 0 to 1
+    pub fn bar_phase(&self, beats_per_bar: u64) -> f64 {
+        let beats_per_bar = beats_per_bar.max(1) as f64;
+        (self.beats % beats_per_bar) / beats_per_bar
+    }
+}
+
+/// A Kira metronome together with the number of passed intervals
+pub(crate) struct ChannelMetronome {
+    pub(crate) handle: MetronomeHandle,
+    /// Intervals in beats and how often they passed
+    pub(crate) intervals: Vec<(f64, u64)>,
+    pub(crate) tempo: f64,
+    /// The estimated position in beats
+    position: f64,
+    /// The number of sync intervals the audio backend reported
+    synced_beats: u64,
+}
+
+impl ChannelMetronome {
+    pub(crate) fn new(handle: MetronomeHandle, intervals: &[f64], tempo: f64) -> Self {
+        ChannelMetronome {
+            handle,
+            intervals: intervals.iter().map(|interval| (*interval, 0)).collect(),
+            tempo,
+            position: 0.0,
+            synced_beats: 0,
+        }
+    }
+
+    /// Advance the estimated position by the frame time, up to the next unreported beat
+    pub(crate) fn advance(&mut self, delta: f64) {
+        let next_beat = self.synced_beats as f64 * SYNC_INTERVAL;
+        self.position = (self.position + delta * self.tempo / 60.0).min(next_beat);
+    }
+
+    pub(crate) fn clock(&self) -> MetronomeClock {
+        MetronomeClock {
+            beats: self.position,
+            tempo: self.tempo,
+        }
+    }
+
+    /// Collect the intervals that passed since the last call as beat events
+    pub(crate) fn beats(&mut self, channel: &AudioChannel) -> Vec<AudioBeat> {
+        let mut beats = vec![];
+        for passed in self.handle.event_iter() {
+            if passed == SYNC_INTERVAL {
+                self.position = self.position.max(self.synced_beats as f64 * SYNC_INTERVAL);
+                self.synced_beats += 1;
+            }
+            if let Some((interval, count)) = self
+                .intervals
+                .iter_mut()
+                .find(|(interval, _)| *interval == passed)
+            {
+                beats.push(AudioBeat {
+                    channel: channel.clone(),
+                    interval: *interval,
+                    beat: *count,
+                });
+                *count += 1;
+            }
+        }
+        beats
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A mixer track that the audio of channels is summed on
+///
+/// Channels play on the master track unless they are assigned to a mixer track.
+/// Changing the volume of a track affects all channels on it, so tracks work well
+/// as buses for categories like music, sound effects or voices.
+/// With the `serde` feature, tracks are serialized as their key.
+/// ```edition2018
+/// # use bevy_kira_audio::{Audio, AudioChannel, AudioMixerTrack};
+/// # use bevy::prelude::*;
+///
+/// fn my_system(audio: Res<Audio>) {
+///     let sfx = AudioMixerTrack::new("sfx".to_owned());
+///     audio.set_channel_track(&AudioChannel::new("footsteps".to_owned()), &sfx);
+///     audio.set_channel_track(&AudioChannel::new("explosions".to_owned()), &sfx);
+///     audio.set_track_volume(0.5, &sfx);
+/// }
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct AudioMixerTrack {
+    key: String,
+}
+
+impl AudioMixerTrack {
+    /// Create a new AudioMixerTrack
+    ///
+    /// ```edition2018
+    /// # use bevy_kira_audio::{Audio, AudioMixerTrack};
+    /// # use bevy::prelude::*;
+    ///
+    /// fn my_system(audio: Res<Audio>) {
+    ///     let music = AudioMixerTrack::new("music".to_owned());
+    ///     audio.set_track_volume(0.8, &music);
+    /// }
+    /// ```
+    pub fn new(key: String) -> Self {
+        AudioMixerTrack { key }
+    }
+
+    /// The key that identifies this track
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl From<String> for AudioMixerTrack {
+    fn from(key: String) -> Self {
+        AudioMixerTrack::new(key)
+    }
+}
+
+impl From<&str> for AudioMixerTrack {
+    fn from(key: &str) -> Self {
+        AudioMixerTrack::new(key.to_owned())
+    }
+}
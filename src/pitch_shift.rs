@@ -0,0 +1,74 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::{CachedValue, Frame, Value};
+use std::f64::consts::PI;
+
+/// Length of the delay window the read heads sweep through, in seconds
+///
+/// Longer windows sound smoother on tonal audio, shorter ones smear transients less.
+const WINDOW_TIME: f64 = 0.05;
+/// The largest shift in either direction, in semitones
+const MAX_PITCH_SHIFT: f64 = 24.0;
+
+/// Shifts the pitch of a mixer track without changing its speed
+///
+/// Kira only changes the pitch by changing the playback speed, so this implements its
+/// effect trait. Two read heads sweep through a short delay line at the shifted rate and
+/// cross-fade, so every sweep starts again close to the input. Large shifts can sound
+/// grainy and the effect delays the track by up to 50 milliseconds.
+#[derive(Debug)]
+pub(crate) struct PitchShifter {
+    /// The shift in semitones
+    semitones: CachedValue<f64>,
+    /// Created on the first frame, when the sample rate is known
+    buffer: Vec<Frame>,
+    write_index: usize,
+    /// Position of the first read head in the window, from 0 to 1
+    phase: f64,
+}
+
+impl PitchShifter {
+    pub(crate) fn new(semitones: Value<f64>) -> Self {
+        PitchShifter {
+            semitones: CachedValue::new(semitones, 0.0),
+            buffer: vec![],
+            write_index: 0,
+            phase: 0.0,
+        }
+    }
+
+    fn read(&self, delay: f64) -> Frame {
+        let length = self.buffer.len();
+        let position = (self.write_index as f64 - delay).rem_euclid(length as f64);
+        let index = position.floor() as usize % length;
+        let next = (index + 1) % length;
+        let fraction = (position - position.floor()) as f32;
+        self.buffer[index] + (self.buffer[next] - self.buffer[index]) * fraction
+    }
+}
+
+impl Effect for PitchShifter {
+    fn process(&mut self, dt: f64, input: Frame, parameters: &Parameters) -> Frame {
+        self.semitones.update(parameters);
+        if self.buffer.is_empty() {
+            let window = (WINDOW_TIME / dt).round().max(4.0) as usize;
+            self.buffer = vec![Frame::from_mono(0.0); window + 2];
+        }
+        self.buffer[self.write_index] = input;
+        let window = (self.buffer.len() - 2) as f64;
+        let semitones = self
+            .semitones
+            .value()
+            .clamp(-MAX_PITCH_SHIFT, MAX_PITCH_SHIFT);
+        let ratio = 2f64.powf(semitones / 12.0);
+        let first_phase = self.phase;
+        let second_phase = (first_phase + 0.5) % 1.0;
+        // Hann windows half a window apart add up to one
+        let first_gain = (PI * first_phase).sin().powi(2) as f32;
+        let output = self.read(first_phase * window) * first_gain
+            + self.read(second_phase * window) * (1.0 - first_gain);
+        self.phase = (self.phase + (1.0 - ratio) / window).rem_euclid(1.0);
+        self.write_index = (self.write_index + 1) % self.buffer.len();
+        output
+    }
+}
@@ -0,0 +1,108 @@
+use crate::conversion::IntoSeconds;
+use crate::FadeCurve;
+use std::time::Duration;
+
+/// Settings for a single play of audio with [`Audio::play_with`](crate::Audio::play_with)
+///
+/// The settings start from the defaults of [`Audio::play`](crate::Audio::play) and
+/// only apply to the played instance. Later changes to its channel still affect it.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, PlaySettings};
+/// # use std::time::Duration;
+///
+/// fn start_music(asset_server: Res<AssetServer>, audio: Res<Audio>) {
+///     audio.play_with(
+///         asset_server.load("music.ogg"),
+///         PlaySettings::new()
+///             .volume(0.6)
+///             .fade_in(Duration::from_secs(2))
+///             .looped(),
+///     );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlaySettings {
+    pub(crate) volume: f32,
+    pub(crate) panning: Option<f32>,
+    pub(crate) playback_rate: f32,
+    pub(crate) fade_in: Option<(Duration, FadeCurve)>,
+    pub(crate) start_position: f64,
+    pub(crate) looped: bool,
+    pub(crate) loop_region: Option<(f64, f64)>,
+}
+
+impl Default for PlaySettings {
+    fn default() -> Self {
+        PlaySettings {
+            volume: 1.0,
+            panning: None,
+            playback_rate: 1.0,
+            fade_in: None,
+            start_position: 0.0,
+            looped: false,
+            loop_region: None,
+        }
+    }
+}
+
+impl PlaySettings {
+    /// Create settings that play the audio like [`Audio::play`](crate::Audio::play)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the volume of the instance, see [`Audio::set_instance_volume`](crate::Audio::set_instance_volume)
+    ///
+    /// The default value is 1. The volume of the channel is applied on top.
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Set the panning of the instance, from 0 (left) to 1 (right)
+    ///
+    /// By default, the instance uses the panning of its channel.
+    pub fn panning(mut self, panning: f32) -> Self {
+        self.panning = Some(panning);
+        self
+    }
+
+    /// Set the playback rate of the instance, which changes its speed and pitch together
+    ///
+    /// The default value is 1. The pitch of the channel is applied on top.
+    pub fn playback_rate(mut self, playback_rate: f32) -> Self {
+        self.playback_rate = playback_rate;
+        self
+    }
+
+    /// Fade the instance in from silence over the given duration
+    pub fn fade_in(self, fade_duration: Duration) -> Self {
+        self.fade_in_with_curve(fade_duration, FadeCurve::Linear)
+    }
+
+    /// Fade the instance in from silence over the given duration using the given fade curve
+    pub fn fade_in_with_curve(mut self, fade_duration: Duration, curve: FadeCurve) -> Self {
+        self.fade_in = Some((fade_duration, curve));
+        self
+    }
+
+    /// Start playing at the given position, see [`Audio::play_from`](crate::Audio::play_from)
+    pub fn start_from(mut self, start_position: impl IntoSeconds) -> Self {
+        self.start_position = start_position.into_seconds();
+        self
+    }
+
+    /// Loop the whole audio source, see [`Audio::play_looped`](crate::Audio::play_looped)
+    pub fn looped(mut self) -> Self {
+        self.looped = true;
+        self
+    }
+
+    /// Loop a part of the audio source, see [`Audio::play_looped_with_region`](crate::Audio::play_looped_with_region)
+    pub fn loop_region(mut self, loop_start: impl IntoSeconds, loop_end: impl IntoSeconds) -> Self {
+        self.looped = true;
+        self.loop_region = Some((loop_start.into_seconds(), loop_end.into_seconds()));
+        self
+    }
+}
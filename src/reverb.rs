@@ -0,0 +1,172 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::{CachedValue, Frame, Value};
+
+/// Delay lengths of the comb filters in samples at 44100 Hz
+const COMB_TUNINGS: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+/// Delay lengths of the all-pass filters in samples at 44100 Hz
+const ALL_PASS_TUNINGS: [usize; 4] = [556, 441, 341, 225];
+/// Extra delay of the right channel to decorrelate it from the left one
+const STEREO_SPREAD: usize = 23;
+const TUNING_SAMPLE_RATE: f64 = 44100.0;
+
+const INPUT_GAIN: f32 = 0.015;
+const DAMPING: f32 = 0.2;
+const ROOM_SCALE: f32 = 0.28;
+const ROOM_OFFSET: f32 = 0.7;
+const WET_SCALE: f32 = 3.0;
+
+/// Settings for the reverb of a mixer track
+///
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioMixerTrack, ReverbSettings};
+///
+/// fn enter_cave(audio: Res<Audio>) {
+///     audio.set_reverb(
+///         &AudioMixerTrack::new("environment".to_owned()),
+///         ReverbSettings {
+///             wet: 0.6,
+///             room_size: 0.9,
+///         },
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReverbSettings {
+    /// How much of the output is reverberated, from 0 (dry) to 1 (fully wet)
+    pub wet: f32,
+    /// How long the reverb rings out, from 0 (small room) to 1 (large hall)
+    pub room_size: f32,
+}
+
+impl Default for ReverbSettings {
+    fn default() -> Self {
+        ReverbSettings {
+            wet: 0.3,
+            room_size: 0.5,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filter_store: f32,
+}
+
+impl Comb {
+    fn new(length: usize) -> Self {
+        Comb {
+            buffer: vec![0.0; length],
+            index: 0,
+            filter_store: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.filter_store = output * (1.0 - DAMPING) + self.filter_store * DAMPING;
+        self.buffer[self.index] = input + self.filter_store * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug)]
+struct AllPass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl AllPass {
+    fn new(length: usize) -> Self {
+        AllPass {
+            buffer: vec![0.0; length],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let delayed = self.buffer[self.index];
+        self.buffer[self.index] = input + delayed * 0.5;
+        self.index = (self.index + 1) % self.buffer.len();
+        delayed - input
+    }
+}
+
+/// One channel of a reverb, made of parallel comb filters followed by all-pass filters
+#[derive(Debug)]
+struct ReverbChannel {
+    combs: Vec<Comb>,
+    all_passes: Vec<AllPass>,
+}
+
+impl ReverbChannel {
+    fn new(sample_rate: f64, spread: usize) -> Self {
+        let scale = |tuning: usize| {
+            (((tuning + spread) as f64 * sample_rate / TUNING_SAMPLE_RATE) as usize).max(1)
+        };
+        ReverbChannel {
+            combs: COMB_TUNINGS.iter().map(|&t| Comb::new(scale(t))).collect(),
+            all_passes: ALL_PASS_TUNINGS
+                .iter()
+                .map(|&t| AllPass::new(scale(t)))
+                .collect(),
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32) -> f32 {
+        let mut output = self
+            .combs
+            .iter_mut()
+            .map(|comb| comb.process(input, feedback))
+            .sum();
+        for all_pass in self.all_passes.iter_mut() {
+            output = all_pass.process(output);
+        }
+        output
+    }
+}
+
+/// A stereo reverb effect based on the Freeverb algorithm
+///
+/// Kira does not come with a reverb, so this implements its effect trait.
+#[derive(Debug)]
+pub(crate) struct Reverb {
+    wet: CachedValue<f64>,
+    room_size: CachedValue<f64>,
+    /// Created on the first frame, when the sample rate is known
+    channels: Option<(ReverbChannel, ReverbChannel)>,
+}
+
+impl Reverb {
+    pub(crate) fn new(wet: Value<f64>, room_size: Value<f64>) -> Self {
+        let defaults = ReverbSettings::default();
+        Reverb {
+            wet: CachedValue::new(wet, defaults.wet as f64),
+            room_size: CachedValue::new(room_size, defaults.room_size as f64),
+            channels: None,
+        }
+    }
+}
+
+impl Effect for Reverb {
+    fn process(&mut self, dt: f64, input: Frame, parameters: &Parameters) -> Frame {
+        self.wet.update(parameters);
+        self.room_size.update(parameters);
+        let (left, right) = self.channels.get_or_insert_with(|| {
+            let sample_rate = 1.0 / dt;
+            (
+                ReverbChannel::new(sample_rate, 0),
+                ReverbChannel::new(sample_rate, STEREO_SPREAD),
+            )
+        });
+        let wet = self.wet.value().clamp(0.0, 1.0) as f32;
+        let feedback = self.room_size.value().clamp(0.0, 1.0) as f32 * ROOM_SCALE + ROOM_OFFSET;
+        let mono = (input.left + input.right) * INPUT_GAIN;
+        let reverberated = Frame::new(left.process(mono, feedback), right.process(mono, feedback));
+        input * (1.0 - wet) + reverberated * (wet * WET_SCALE)
+    }
+}
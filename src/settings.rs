@@ -0,0 +1,192 @@
+use crate::limiter::LimiterSettings;
+use crate::source::AudioFormat;
+use crate::spatial::ListenerMixing;
+use bevy::utils::HashMap;
+use std::time::Duration;
+
+/// Settings for the audio backend
+///
+/// Insert this resource before adding the [`AudioPlugin`](crate::AudioPlugin) to raise
+/// the limits of the audio manager. Sounds, arrangements and instances beyond these
+/// limits fail to play.
+/// ```edition2018,no_run
+/// # use bevy_kira_audio::{AudioPlugin, AudioSettings};
+/// # use bevy::prelude::*;
+/// fn main() {
+///    App::build()
+///         .add_resource(AudioSettings {
+///             num_sounds: 500,
+///             ..Default::default()
+///         })
+///         .add_plugins(DefaultPlugins)
+///         .add_plugin(AudioPlugin)
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AudioSettings {
+    /// The maximum number of loaded audio sources
+    ///
+    /// Audio sources that are looped as a whole take up one more for a short sound
+    /// that smooths the wrap of the loop.
+    pub num_sounds: usize,
+    /// The maximum number of arrangements
+    ///
+    /// One arrangement is created per audio source, looping setting and channel.
+    pub num_arrangements: usize,
+    /// The maximum number of arrangements to keep for playing the same audio again
+    ///
+    /// When a new arrangement is needed, the one that was played least recently is
+    /// removed, unless instances still play from it. Keep this below `num_arrangements`
+    /// to play any number of different audio sources without running out of arrangements.
+    pub max_cached_arrangements: Option<usize>,
+    /// The maximum number of instances playing at the same time
+    pub num_instances: usize,
+    /// Continue playing instances with the new data when their audio source is hot reloaded
+    ///
+    /// The instances restart from their position at the time of the reload.
+    /// Paused instances stay paused. Otherwise, instances of modified audio sources are stopped.
+    pub restart_on_reload: bool,
+    /// Run the audio backend without an audio device
+    ///
+    /// Nothing is output, but instances play, finish and report their state as usual.
+    /// Instead of on an audio thread, the audio is processed in the audio system,
+    /// advancing by the time of the bevy frame or by
+    /// [`headless_frames_per_update`](AudioSettings::headless_frames_per_update).
+    /// This makes it possible to play audio
+    /// in tests, update the app and check the [`AudioState`](crate::AudioState).
+    /// ```edition2018,no_run
+    /// # use bevy_kira_audio::{AudioPlugin, AudioSettings};
+    /// # use bevy::prelude::*;
+    /// fn main() {
+    ///    App::build()
+    ///         .add_resource(AudioSettings {
+    ///             headless: true,
+    ///             ..Default::default()
+    ///         })
+    ///         .add_plugins(MinimalPlugins)
+    ///         .add_plugin(AudioPlugin)
+    ///         .run();
+    /// }
+    /// ```
+    #[cfg(feature = "headless")]
+    pub headless: bool,
+    /// Process this many frames in every update when running without an audio device
+    ///
+    /// By default, the headless backend processes the time that passed since the last
+    /// update. With a fixed number of frames, every update advances the audio by the same
+    /// amount, independent of how long the update took. The playback positions, cooldowns,
+    /// load timeouts and metronomes follow the processed audio as well. This makes tests
+    /// reproducible. The backend runs at 48000 frames per second.
+    #[cfg(feature = "headless")]
+    pub headless_frames_per_update: Option<u32>,
+    /// The file extensions to register the loader of a format for
+    ///
+    /// Formats without an entry use their
+    /// [`default_extensions`](crate::AudioFormat::default_extensions).
+    /// The extensions are matched without the leading dot.
+    /// ```edition2018,no_run
+    /// # use bevy_kira_audio::{AudioFormat, AudioPlugin, AudioSettings};
+    /// # use bevy::prelude::*;
+    /// fn main() {
+    ///    let mut settings = AudioSettings::default();
+    ///    settings.extensions.insert(AudioFormat::Ogg, vec!["ogg", "oga"]);
+    ///    settings.extensions.insert(AudioFormat::Wav, vec!["wav", "wave"]);
+    ///    App::build()
+    ///         .add_resource(settings)
+    ///         .add_plugins(DefaultPlugins)
+    ///         .add_plugin(AudioPlugin)
+    ///         .run();
+    /// }
+    /// ```
+    pub extensions: HashMap<AudioFormat, Vec<&'static str>>,
+    /// Limit the volume of the whole output from the start to prevent clipping
+    ///
+    /// See [`Audio::set_master_limiter`](crate::Audio::set_master_limiter).
+    pub master_limiter: Option<LimiterSettings>,
+    /// Wait for [`Audio::initialize`](crate::Audio::initialize) before creating the audio device
+    ///
+    /// Browsers only allow audio after the user interacted with the page. Until the audio is
+    /// initialized, [`Audio::is_available`](crate::Audio::is_available) is `false` and all
+    /// commands are kept to run once it is.
+    pub defer_initialization: bool,
+    /// Fade out all audio over this duration when the app exits
+    ///
+    /// Stopping loud audio abruptly can be heard as a click. When an `AppExit` event is
+    /// sent before the audio stage, the plugin holds it back and keeps running frames until
+    /// the fade is done. See [`Audio::fade_out_all`](crate::Audio::fade_out_all).
+    pub fade_out_on_exit: Option<Duration>,
+    /// The maximum number of audio commands to run per frame
+    ///
+    /// Commands beyond the limit are delayed to the next frame and a warning is logged.
+    /// Setters that are overridden before any other command, like several volume changes
+    /// of the same channel while dragging a slider, are always dropped before counting.
+    /// A limit of 0 is treated as 1, so queued commands always make progress.
+    pub max_commands_per_frame: Option<usize>,
+    /// The maximum number of preloaded audio sources to add to Kira per frame
+    ///
+    /// Adding a source copies its audio data on the main thread. Preloading many
+    /// sources that finished loading at once can stall a frame, so the rest is spread
+    /// over the following frames. See [`Audio::preload`](crate::Audio::preload).
+    ///
+    /// In a release build, preloading 100 OGG files of 7.5 seconds each took a single
+    /// frame of about 40 milliseconds. With a limit of 10, no frame took longer than 7.
+    /// A limit of 0 is treated as 1.
+    pub max_preloads_per_frame: Option<usize>,
+    /// How long a command waits for its audio source to load before it is dropped
+    ///
+    /// Commands that play audio wait until their audio source is loaded. If it never
+    /// loads, for example because of a typo in the asset path or a file that fails to
+    /// decode, the command is dropped and reported as an
+    /// [`AudioError::LoadTimeout`](crate::AudioError::LoadTimeout). Bevy keeps such
+    /// assets in the loading state, and later commands in the same channel wait for the
+    /// command, so they would otherwise never run either.
+    /// `None` waits forever. The default is 30 seconds.
+    pub load_timeout: Option<Duration>,
+    /// How emitters are heard when there are several audio listeners, see [`ListenerMixing`]
+    ///
+    /// The default is [`ListenerMixing::Loudest`].
+    pub listener_mixing: ListenerMixing,
+}
+
+impl AudioSettings {
+    /// The file extensions to register the loader of the given format for
+    #[cfg(any(
+        feature = "mp3",
+        feature = "ogg",
+        feature = "wav",
+        feature = "flac",
+        feature = "aac",
+        feature = "opus"
+    ))]
+    pub(crate) fn loader_extensions(&self, format: AudioFormat) -> Vec<&'static str> {
+        self.extensions
+            .get(&format)
+            .cloned()
+            .unwrap_or_else(|| format.default_extensions().to_vec())
+    }
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            num_sounds: 100,
+            num_arrangements: 100,
+            max_cached_arrangements: None,
+            num_instances: 100,
+            restart_on_reload: false,
+            #[cfg(feature = "headless")]
+            headless: false,
+            #[cfg(feature = "headless")]
+            headless_frames_per_update: None,
+            extensions: HashMap::default(),
+            master_limiter: None,
+            defer_initialization: false,
+            fade_out_on_exit: None,
+            max_commands_per_frame: None,
+            max_preloads_per_frame: None,
+            load_timeout: Some(Duration::from_secs(30)),
+            listener_mixing: ListenerMixing::Loudest,
+        }
+    }
+}
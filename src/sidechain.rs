@@ -0,0 +1,112 @@
+use kira::mixer::effect::Effect;
+use kira::parameter::Parameters;
+use kira::Frame;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Settings for a sidechain compressor
+///
+/// The compressor lowers the volume of one mixer track while the audio of another
+/// track, the key, is louder than the threshold.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioMixerTrack, SidechainSettings};
+///
+/// fn duck_music_under_voices(audio: Res<Audio>) {
+///     audio.add_sidechain(
+///         &AudioMixerTrack::new("music".to_owned()),
+///         &AudioMixerTrack::new("voices".to_owned()),
+///         SidechainSettings {
+///             ratio: 8.0,
+///             ..Default::default()
+///         },
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SidechainSettings {
+    /// The amplitude of the key from which on the compressed track gets quieter, from 0 to 1
+    pub threshold: f32,
+    /// How strongly the compressed track is lowered
+    ///
+    /// With a ratio of 4, a key that is 12 dB above the threshold lowers the compressed
+    /// track by 9 dB, as if the key was compressed to 3 dB above the threshold.
+    pub ratio: f32,
+    /// How fast the compressor reacts to a louder key
+    pub attack: Duration,
+    /// How long the compressed track takes to recover once the key gets quieter
+    pub release: Duration,
+}
+
+impl Default for SidechainSettings {
+    fn default() -> Self {
+        SidechainSettings {
+            threshold: 0.1,
+            ratio: 4.0,
+            attack: Duration::from_millis(10),
+            release: Duration::from_millis(250),
+        }
+    }
+}
+
+/// The level of the key track, shared between the two effects of a sidechain
+pub(crate) type KeyLevel = Arc<AtomicU32>;
+
+/// Measures the peak level of the key track, without changing its audio
+#[derive(Debug)]
+pub(crate) struct SidechainDetector {
+    level: KeyLevel,
+}
+
+impl SidechainDetector {
+    pub(crate) fn new(level: KeyLevel) -> Self {
+        SidechainDetector { level }
+    }
+}
+
+impl Effect for SidechainDetector {
+    fn process(&mut self, _dt: f64, input: Frame, _parameters: &Parameters) -> Frame {
+        let peak = input.left.abs().max(input.right.abs());
+        self.level.store(peak.to_bits(), Ordering::Relaxed);
+        input
+    }
+}
+
+/// Lowers the volume of the compressed track based on the level of the key track
+#[derive(Debug)]
+pub(crate) struct SidechainCompressor {
+    level: KeyLevel,
+    settings: SidechainSettings,
+    envelope: f64,
+}
+
+impl SidechainCompressor {
+    pub(crate) fn new(level: KeyLevel, settings: SidechainSettings) -> Self {
+        SidechainCompressor {
+            level,
+            settings,
+            envelope: 0.0,
+        }
+    }
+}
+
+impl Effect for SidechainCompressor {
+    fn process(&mut self, dt: f64, input: Frame, _parameters: &Parameters) -> Frame {
+        let key = f32::from_bits(self.level.load(Ordering::Relaxed)) as f64;
+        let time = if key > self.envelope {
+            self.settings.attack
+        } else {
+            self.settings.release
+        };
+        let time = time.as_secs_f64().max(dt);
+        self.envelope = key + (self.envelope - key) * (-dt / time).exp();
+        let threshold = (self.settings.threshold as f64).max(f64::EPSILON);
+        if self.envelope <= threshold {
+            return input;
+        }
+        let ratio = (self.settings.ratio as f64).max(1.0);
+        let gain = (self.envelope / threshold).powf(1.0 / ratio - 1.0);
+        input * gain as f32
+    }
+}
@@ -0,0 +1,53 @@
+use crate::channel::AudioChannel;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The volume, panning and pitch of all channels
+///
+/// Take a snapshot with [`Audio::snapshot`](crate::Audio::snapshot) and apply it again
+/// with [`Audio::restore`](crate::Audio::restore). With the `serde` feature, snapshots
+/// can be serialized, for example to persist the mix of a settings menu or to load the
+/// default mix of all channels from a config file and restore it at startup.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioMixerSnapshot};
+///
+/// fn save_mix(audio: Res<Audio>, mut saved: ResMut<AudioMixerSnapshot>) {
+///     *saved = audio.snapshot();
+/// }
+///
+/// fn load_mix(audio: Res<Audio>, saved: Res<AudioMixerSnapshot>) {
+///     audio.restore(&saved);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AudioMixerSnapshot {
+    /// The settings of all channels that were configured
+    ///
+    /// Channels without an entry use the default settings.
+    pub channels: HashMap<AudioChannel, ChannelMixerSettings>,
+}
+
+/// The volume, panning and pitch of a channel
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChannelMixerSettings {
+    /// The volume of the channel, see [`Audio::set_volume_in_channel`](crate::Audio::set_volume_in_channel)
+    pub volume: f32,
+    /// The panning of the channel, see [`Audio::set_panning_in_channel`](crate::Audio::set_panning_in_channel)
+    pub panning: f32,
+    /// The pitch of the channel, see [`Audio::set_pitch_in_channel`](crate::Audio::set_pitch_in_channel)
+    pub pitch: f32,
+}
+
+impl Default for ChannelMixerSettings {
+    fn default() -> Self {
+        ChannelMixerSettings {
+            volume: 1.0,
+            panning: 0.5,
+            pitch: 1.0,
+        }
+    }
+}
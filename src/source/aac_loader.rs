@@ -0,0 +1,128 @@
+#[cfg(feature = "aac")]
+use anyhow::{Error, Result};
+#[cfg(feature = "aac")]
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+#[cfg(feature = "aac")]
+use bevy::utils::BoxedFuture;
+#[cfg(feature = "aac")]
+use kira::sound::error::SoundFromFileError;
+#[cfg(feature = "aac")]
+use kira::sound::SoundSettings;
+#[cfg(feature = "aac")]
+use kira::Frame;
+#[cfg(feature = "aac")]
+use std::io::{Cursor, ErrorKind};
+#[cfg(feature = "aac")]
+use symphonia::core::{
+    audio::SampleBuffer, codecs::DecoderOptions, errors::Error as SymphoniaError,
+    formats::FormatOptions, io::MediaSourceStream, meta::MetadataOptions, probe::Hint,
+};
+
+#[cfg(feature = "aac")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "aac")]
+use crate::source::AudioLoadSettings;
+#[cfg(feature = "aac")]
+use crate::source::AudioSource;
+
+#[cfg(feature = "aac")]
+pub struct AacLoader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
+
+#[cfg(feature = "aac")]
+impl AssetLoader for AacLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_aac(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
+
+/// Decode AAC audio, either as a raw ADTS stream or in an MP4 container
+#[cfg(feature = "aac")]
+pub(crate) fn decode_aac(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let source = MediaSourceStream::new(Box::new(Cursor::new(bytes.to_vec())), Default::default());
+    let mut format = symphonia::default::get_probe()
+        .format(
+            &Hint::new(),
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?
+        .format;
+    let track = match format.default_track() {
+        Some(track) => track,
+        None => return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat)),
+    };
+    let track_id = track.id;
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+    let mut sample_rate = track.codec_params.sample_rate;
+    let mut stereo_samples = vec![];
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error)) if error.kind() == ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(error) => return Err(error.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = decoder.decode(&packet)?;
+        let spec = *decoded.spec();
+        sample_rate.get_or_insert(spec.rate);
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        match spec.channels.count() {
+            1 => {
+                for sample in buffer.samples() {
+                    stereo_samples.push(Frame::new(*sample, *sample))
+                }
+            }
+            2 => {
+                let mut iter = buffer.samples().iter();
+                while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
+                    stereo_samples.push(Frame::new(*left, *right))
+                }
+            }
+            _ => {
+                return Err(Error::from(
+                    SoundFromFileError::UnsupportedChannelConfiguration,
+                ))
+            }
+        }
+    }
+    let sample_rate = match sample_rate {
+        Some(sample_rate) => sample_rate,
+        None => return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat)),
+    };
+
+    Ok(create_audio_source(
+        sample_rate,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
+}
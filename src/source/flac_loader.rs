@@ -9,15 +9,22 @@ use claxon::FlacReader;
 #[cfg(feature = "flac")]
 use kira::sound::error::SoundFromFileError;
 #[cfg(feature = "flac")]
-use kira::sound::{Sound, SoundSettings};
+use kira::sound::SoundSettings;
 #[cfg(feature = "flac")]
 use kira::Frame;
 
+#[cfg(feature = "flac")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "flac")]
+use crate::source::AudioLoadSettings;
 #[cfg(feature = "flac")]
 use crate::source::AudioSource;
 
-#[derive(Default)]
-pub struct FlacLoader;
+#[cfg(feature = "flac")]
+pub struct FlacLoader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
 
 #[cfg(feature = "flac")]
 impl AssetLoader for FlacLoader {
@@ -27,49 +34,54 @@ impl AssetLoader for FlacLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut reader = FlacReader::new(bytes)?;
-            let stream_info = reader.streaminfo();
-            let mut stereo_samples = vec![];
-            match reader.streaminfo().channels {
-                1 => {
-                    for sample in reader.samples() {
-                        let sample = sample?;
-                        stereo_samples.push(Frame::from_i32(
-                            sample,
-                            sample,
-                            stream_info.bits_per_sample,
-                        ));
-                    }
-                }
-                2 => {
-                    let mut iter = reader.samples();
-                    while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
-                        stereo_samples.push(Frame::from_i32(
-                            left?,
-                            right?,
-                            stream_info.bits_per_sample,
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(Error::from(
-                        SoundFromFileError::UnsupportedChannelConfiguration,
-                    ))
-                }
-            }
-
-            load_context.set_default_asset(LoadedAsset::new(AudioSource {
-                sound: Sound::from_frames(
-                    stream_info.sample_rate,
-                    stereo_samples,
-                    SoundSettings::default(),
-                ),
-            }));
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_flac(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["flac"]
+        &self.extensions
     }
 }
+
+#[cfg(feature = "flac")]
+pub(crate) fn decode_flac(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let mut reader = FlacReader::new(bytes)?;
+    let stream_info = reader.streaminfo();
+    let mut stereo_samples = vec![];
+    match reader.streaminfo().channels {
+        1 => {
+            for sample in reader.samples() {
+                let sample = sample?;
+                stereo_samples.push(Frame::from_i32(sample, sample, stream_info.bits_per_sample));
+            }
+        }
+        2 => {
+            let mut iter = reader.samples();
+            while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
+                stereo_samples.push(Frame::from_i32(left?, right?, stream_info.bits_per_sample));
+            }
+        }
+        _ => {
+            return Err(Error::from(
+                SoundFromFileError::UnsupportedChannelConfiguration,
+            ))
+        }
+    }
+
+    Ok(create_audio_source(
+        stream_info.sample_rate,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
+}
@@ -0,0 +1,110 @@
+use crate::source::ResampleSettings;
+use kira::sound::SoundSettings;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Sound settings that are applied to audio sources while they are loaded
+///
+/// The settings are looked up by the asset path of the audio source, relative to the
+/// assets folder. They have to be set before the asset is loaded; changing them
+/// afterwards only affects sources that are loaded or reloaded later.
+///
+/// The semantic duration of a sound is used as its loop end, and its default loop start
+/// as the point looped instances jump back to.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioLoadSettings, SoundSettings};
+///
+/// fn start_music(
+///     load_settings: Res<AudioLoadSettings>,
+///     asset_server: Res<AssetServer>,
+///     audio: Res<Audio>,
+/// ) {
+///     load_settings.set(
+///         "music.ogg",
+///         SoundSettings::new()
+///             .default_loop_start(4.0)
+///             .semantic_duration(36.0),
+///     );
+///     audio.play_looped(asset_server.load("music.ogg"));
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct AudioLoadSettings {
+    settings: Arc<RwLock<HashMap<PathBuf, SoundSettings>>>,
+    resampling: Arc<RwLock<Option<ResampleSettings>>>,
+}
+
+impl AudioLoadSettings {
+    /// Use the given sound settings for the audio source at the given asset path
+    ///
+    /// The id of the settings is ignored; every loaded sound gets a new one.
+    pub fn set<P: Into<PathBuf>>(&self, path: P, settings: SoundSettings) {
+        self.settings.write().insert(path.into(), settings);
+    }
+
+    /// Load the audio source at the given asset path with the default sound settings again
+    pub fn remove<P: AsRef<Path>>(&self, path: P) {
+        self.settings.write().remove(path.as_ref());
+    }
+
+    /// Resample all audio sources that are loaded afterwards to the given sample rate
+    ///
+    /// By default, sources keep their sample rate and Kira interpolates between their
+    /// samples during playback. See [`ResampleSettings`] for when resampling helps.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioLoadSettings, ResampleSettings, ResamplingQuality};
+    ///
+    /// fn setup(load_settings: Res<AudioLoadSettings>, asset_server: Res<AssetServer>) {
+    ///     load_settings.set_resampling(Some(ResampleSettings {
+    ///         sample_rate: 48000,
+    ///         quality: ResamplingQuality::Sinc,
+    ///     }));
+    ///     let _music = asset_server.load::<bevy_kira_audio::AudioSource, _>("music_44100hz.ogg");
+    /// }
+    /// ```
+    pub fn set_resampling(&self, resampling: Option<ResampleSettings>) {
+        *self.resampling.write() = resampling;
+    }
+
+    /// How to resample the audio sources that are loaded
+    #[cfg_attr(
+        not(any(
+            feature = "mp3",
+            feature = "ogg",
+            feature = "wav",
+            feature = "flac",
+            feature = "aac",
+            feature = "opus"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn resampling(&self) -> Option<ResampleSettings> {
+        *self.resampling.read()
+    }
+
+    /// The sound settings to load the audio source at the given asset path with
+    #[cfg_attr(
+        not(any(
+            feature = "mp3",
+            feature = "ogg",
+            feature = "wav",
+            feature = "flac",
+            feature = "aac",
+            feature = "opus"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn get(&self, path: &Path) -> SoundSettings {
+        match self.settings.read().get(path) {
+            Some(settings) => SoundSettings {
+                id: SoundSettings::default().id,
+                ..settings.clone()
+            },
+            None => SoundSettings::default(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use kira::arrangement::{Arrangement, ArrangementSettings, SoundClip};
+use kira::sound::handle::SoundHandle;
+use kira::sound::{Sound, SoundSettings};
+use kira::Frame;
+
+/// The frames at both ends of an audio source, to loop it without a click
+///
+/// Kira interpolates the frames around each position of a sound and treats everything
+/// before its start and after its end as silence. When a loop wraps around, the frames
+/// next to the wrap are interpolated towards that silence instead of towards the other
+/// end of the sound, which can be heard as a click.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LoopSeam {
+    sample_rate: u32,
+    first: Frame,
+    second: Frame,
+    last: Frame,
+}
+
+impl LoopSeam {
+    pub(crate) fn new(sample_rate: u32, frames: &[Frame]) -> Option<Self> {
+        if sample_rate == 0 || frames.len() < 2 {
+            return None;
+        }
+        Some(LoopSeam {
+            sample_rate,
+            first: frames[0],
+            second: frames[1],
+            last: frames[frames.len() - 1],
+        })
+    }
+
+    /// A short sound with the frames that the interpolation around the wrap is missing
+    ///
+    /// The cubic interpolation is linear in the frames, so adding it for the missing
+    /// frames with silence in between corrects the interpolation of the looped sound.
+    pub(crate) fn sound(&self) -> Sound {
+        let mut frames = vec![Frame::from_mono(0.0); 8];
+        frames[0] = self.last;
+        frames[6] = self.first;
+        frames[7] = self.second;
+        Sound::from_frames(self.sample_rate, frames, SoundSettings::default())
+    }
+
+    /// An arrangement that loops the whole sound, with the seam sound at the wrap
+    pub(crate) fn loop_arrangement(
+        &self,
+        sound_handle: &SoundHandle,
+        seam_handle: &SoundHandle,
+    ) -> Arrangement {
+        let frame = 1.0 / self.sample_rate as f64;
+        let duration = sound_handle.duration();
+        let mut arrangement = Arrangement::new(
+            ArrangementSettings::new()
+                .cooldown(0.0)
+                .default_loop_start(0.0),
+        );
+        arrangement
+            .add_clip(SoundClip::new(sound_handle, 0.0))
+            // The first frame interpolates from the last one
+            .add_clip(SoundClip {
+                sound_id: seam_handle.id(),
+                clip_time_range: (0.0, frame),
+                sound_time_range: (frame, 2.0 * frame),
+            })
+            // The last two frames interpolate towards the first ones
+            .add_clip(SoundClip {
+                sound_id: seam_handle.id(),
+                clip_time_range: (duration - 2.0 * frame, duration),
+                sound_time_range: (4.0 * frame, 6.0 * frame),
+            });
+        arrangement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kira::instance::InstanceSettings;
+    use kira::manager::{AudioManager, AudioManagerSettings};
+    use std::f32::consts::PI;
+
+    /// Differs from the output sample rate, so the positions fall between the frames
+    const SAMPLE_RATE: u32 = 44_100;
+    /// The sample rate of Kira's backend without an audio thread
+    const OUTPUT_SAMPLE_RATE: f32 = 48_000.0;
+    const FREQUENCY: f32 = 450.0;
+    const AMPLITUDE: f32 = 0.5;
+
+    /// 45 whole periods of a cosine, which starts and ends at its peak
+    fn cosine_frames() -> Vec<Frame> {
+        (0..SAMPLE_RATE as usize / 10)
+            .map(|index| {
+                let time = index as f32 / SAMPLE_RATE as f32;
+                Frame::from_mono((2.0 * PI * FREQUENCY * time).cos() * AMPLITUDE)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn looped_cosine_is_continuous_across_the_wrap() {
+        let frames = cosine_frames();
+        let seam = LoopSeam::new(SAMPLE_RATE, &frames).unwrap();
+        let (mut manager, mut backend) =
+            AudioManager::new_without_audio_thread(AudioManagerSettings::default());
+        let sound_handle = manager
+            .add_sound(Sound::from_frames(
+                SAMPLE_RATE,
+                frames,
+                SoundSettings::default(),
+            ))
+            .unwrap();
+        let seam_handle = manager.add_sound(seam.sound()).unwrap();
+        let mut arrangement = manager
+            .add_arrangement(seam.loop_arrangement(&sound_handle, &seam_handle))
+            .unwrap();
+        arrangement.play(InstanceSettings::default()).unwrap();
+
+        // A quarter of a second wraps the loop twice
+        let output: Vec<f32> = (0..12_000).map(|_| backend.process().left).collect();
+        let start = output.iter().position(|sample| *sample != 0.0).unwrap();
+        // The largest step between two output frames of the continuous cosine
+        let max_step = 2.0 * PI * FREQUENCY * AMPLITUDE / OUTPUT_SAMPLE_RATE;
+        for (index, pair) in output[start..].windows(2).enumerate() {
+            let step = (pair[1] - pair[0]).abs();
+            assert!(
+                step < max_step * 1.5,
+                "step of {} at output frame {}",
+                step,
+                start + index
+            );
+        }
+    }
+}
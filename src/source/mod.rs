@@ -1,19 +1,208 @@
+mod aac_loader;
 mod flac_loader;
+mod load_settings;
+mod loop_seam;
 mod mp3_loader;
 mod ogg_loader;
+mod opus_loader;
+mod resample;
 mod wav_loader;
 
+use anyhow::Result;
 use bevy_reflect::TypeUuid;
-use kira::sound::Sound;
+use kira::sound::{Sound, SoundSettings};
+use kira::Frame;
+use resample::create_audio_source;
 
+#[cfg(feature = "aac")]
+pub use aac_loader::AacLoader;
+#[cfg(feature = "flac")]
 pub use flac_loader::FlacLoader;
+pub use load_settings::AudioLoadSettings;
+#[cfg(feature = "mp3")]
 pub use mp3_loader::Mp3Loader;
+#[cfg(feature = "ogg")]
 pub use ogg_loader::OggLoader;
+#[cfg(feature = "opus")]
+pub use opus_loader::OpusLoader;
+pub use resample::{ResampleSettings, ResamplingQuality};
+#[cfg(feature = "wav")]
 pub use wav_loader::WavLoader;
 
+pub(crate) use loop_seam::LoopSeam;
+
 /// A source of audio data
 #[derive(Debug, Clone, TypeUuid)]
 #[uuid = "7a14806a-672b-443b-8d16-4f18afefa463"]
 pub struct AudioSource {
     pub(crate) sound: Sound,
+    /// The frames to loop the whole source without a click, `None` if it is too short
+    pub(crate) loop_seam: Option<LoopSeam>,
+}
+
+/// The encoding of audio data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioFormat {
+    /// Mp3, requires the "mp3" feature
+    Mp3,
+    /// Ogg Vorbis, requires the "ogg" feature
+    Ogg,
+    /// Wav, requires the "wav" feature
+    Wav,
+    /// Flac, requires the "flac" feature
+    Flac,
+    /// AAC, either raw or in an M4A container, requires the "aac" feature
+    Aac,
+    /// Opus in an Ogg container, requires the "opus" feature
+    Opus,
+}
+
+impl AudioFormat {
+    /// Get the format belonging to a file extension, like "ogg"
+    ///
+    /// Returns `None` for unrecognized extensions.
+    pub fn from_extension(extension: &str) -> Option<AudioFormat> {
+        match extension.to_lowercase().as_str() {
+            "mp3" => Some(AudioFormat::Mp3),
+            "ogg" => Some(AudioFormat::Ogg),
+            "wav" => Some(AudioFormat::Wav),
+            "flac" => Some(AudioFormat::Flac),
+            "aac" | "m4a" => Some(AudioFormat::Aac),
+            "opus" => Some(AudioFormat::Opus),
+            _ => None,
+        }
+    }
+
+    /// The file extensions that the loader of this format is registered for by default
+    pub fn default_extensions(&self) -> &'static [&'static str] {
+        match self {
+            AudioFormat::Mp3 => &["mp3"],
+            AudioFormat::Ogg => &["ogg"],
+            AudioFormat::Wav => &["wav"],
+            AudioFormat::Flac => &["flac"],
+            AudioFormat::Aac => &["aac", "m4a"],
+            AudioFormat::Opus => &["opus"],
+        }
+    }
+}
+
+impl AudioSource {
+    /// The duration of the audio in seconds
+    ///
+    /// The duration is known as soon as the asset is loaded. Before that,
+    /// `Assets<AudioSource>` does not contain the source yet.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::AudioSource;
+    ///
+    /// fn track_length(asset_server: Res<AssetServer>, audio_sources: Res<Assets<AudioSource>>) {
+    ///     let track: Handle<AudioSource> = asset_server.load("music.ogg");
+    ///     if let Some(duration) = audio_sources.get(&track).map(|source| source.duration()) {
+    ///         info!("The track is {} seconds long", duration);
+    ///     }
+    /// }
+    /// ```
+    pub fn duration(&self) -> f64 {
+        self.sound.duration()
+    }
+
+    /// Create an audio source from frames, for example of procedurally generated audio
+    ///
+    /// The source does not need any decoder feature. Add it to `Assets<AudioSource>`
+    /// to play it like any loaded audio.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioSource, Frame};
+    /// # use std::f32::consts::PI;
+    ///
+    /// fn play_tone(mut audio_sources: ResMut<Assets<AudioSource>>, audio: Res<Audio>) {
+    ///     let sample_rate = 48_000;
+    ///     let frames = (0..sample_rate / 2)
+    ///         .map(|index| {
+    ///             let time = index as f32 / sample_rate as f32;
+    ///             Frame::from_mono((2.0 * PI * 440.0 * time).sin() * 0.3)
+    ///         })
+    ///         .collect();
+    ///     let tone = audio_sources.add(AudioSource::from_frames(sample_rate, frames));
+    ///     audio.play(tone);
+    /// }
+    /// ```
+    pub fn from_frames(sample_rate: u32, frames: Vec<Frame>) -> AudioSource {
+        create_audio_source(sample_rate, frames, SoundSettings::default(), None)
+    }
+
+    /// Create an audio source from mono samples, see [`AudioSource::from_frames`]
+    pub fn from_samples(sample_rate: u32, samples: &[f32]) -> AudioSource {
+        Self::from_frames(
+            sample_rate,
+            samples.iter().copied().map(Frame::from_mono).collect(),
+        )
+    }
+
+    /// Create an audio source from a Kira sound that was built by hand
+    ///
+    /// Kira does not give access to the frames of a sound, so looping the whole source
+    /// can click at the wrap. Prefer [`AudioSource::from_frames`] for looped audio.
+    pub fn from_sound(sound: Sound) -> AudioSource {
+        AudioSource {
+            sound,
+            loop_seam: None,
+        }
+    }
+
+    /// Decode audio data that is already in memory
+    ///
+    /// This uses the same decoders as the asset loaders, so the feature for the
+    /// format has to be enabled. Add the returned source to `Assets<AudioSource>`
+    /// to play it.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{Audio, AudioFormat, AudioSource};
+    ///
+    /// fn play_downloaded(bytes: Vec<u8>, mut audio_sources: ResMut<Assets<AudioSource>>, audio: Res<Audio>) {
+    ///     match AudioSource::from_bytes(&bytes, AudioFormat::Ogg) {
+    ///         Ok(audio_source) => { audio.play(audio_sources.add(audio_source)); }
+    ///         Err(error) => error!("Failed to decode audio: {:?}", error),
+    ///     }
+    /// }
+    /// ```
+    #[cfg_attr(
+        not(all(
+            feature = "mp3",
+            feature = "ogg",
+            feature = "wav",
+            feature = "flac",
+            feature = "aac",
+            feature = "opus"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn from_bytes(bytes: &[u8], format: AudioFormat) -> Result<AudioSource> {
+        match format {
+            #[cfg(feature = "mp3")]
+            AudioFormat::Mp3 => mp3_loader::decode_mp3(bytes, SoundSettings::default(), None),
+            #[cfg(feature = "ogg")]
+            AudioFormat::Ogg => ogg_loader::decode_ogg(bytes, SoundSettings::default(), None),
+            #[cfg(feature = "wav")]
+            AudioFormat::Wav => wav_loader::decode_wav(bytes, SoundSettings::default(), None),
+            #[cfg(feature = "flac")]
+            AudioFormat::Flac => flac_loader::decode_flac(bytes, SoundSettings::default(), None),
+            #[cfg(feature = "aac")]
+            AudioFormat::Aac => aac_loader::decode_aac(bytes, SoundSettings::default(), None),
+            #[cfg(feature = "opus")]
+            AudioFormat::Opus => opus_loader::decode_opus(bytes, SoundSettings::default(), None),
+            #[cfg(not(all(
+                feature = "mp3",
+                feature = "ogg",
+                feature = "wav",
+                feature = "flac",
+                feature = "aac",
+                feature = "opus"
+            )))]
+            _ => Err(anyhow::anyhow!(
+                "Support for the audio format {:?} is not enabled",
+                format
+            )),
+        }
+    }
 }
@@ -7,18 +7,25 @@ use bevy::utils::BoxedFuture;
 #[cfg(feature = "mp3")]
 use kira::sound::error::SoundFromFileError;
 #[cfg(feature = "mp3")]
-use kira::sound::{Sound, SoundSettings};
+use kira::sound::SoundSettings;
 #[cfg(feature = "mp3")]
 use kira::Frame;
 
 #[cfg(feature = "mp3")]
 use minimp3;
 
+#[cfg(feature = "mp3")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "mp3")]
+use crate::source::AudioLoadSettings;
 #[cfg(feature = "mp3")]
 use crate::source::AudioSource;
 
-#[derive(Default)]
-pub struct Mp3Loader;
+#[cfg(feature = "mp3")]
+pub struct Mp3Loader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
 
 #[cfg(feature = "mp3")]
 impl AssetLoader for Mp3Loader {
@@ -28,71 +35,78 @@ impl AssetLoader for Mp3Loader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut decoder = minimp3::Decoder::new(bytes);
-            let mut sample_rate = None;
-            let mut stereo_samples = vec![];
-            loop {
-                match decoder.next_frame() {
-                    Ok(frame) => {
-                        if let Some(sample_rate) = sample_rate {
-                            if sample_rate != frame.sample_rate {
-                                return Err(Error::from(
-                                    SoundFromFileError::UnsupportedAudioFileFormat,
-                                ));
-                            }
-                        } else {
-                            sample_rate = Some(frame.sample_rate);
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_mp3(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
+
+#[cfg(feature = "mp3")]
+pub(crate) fn decode_mp3(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let mut decoder = minimp3::Decoder::new(bytes);
+    let mut sample_rate = None;
+    let mut stereo_samples = vec![];
+    loop {
+        match decoder.next_frame() {
+            Ok(frame) => {
+                if let Some(sample_rate) = sample_rate {
+                    if sample_rate != frame.sample_rate {
+                        return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat));
+                    }
+                } else {
+                    sample_rate = Some(frame.sample_rate);
+                }
+                match frame.channels {
+                    1 => {
+                        for sample in frame.data {
+                            stereo_samples.push(Frame::from_i32(sample.into(), sample.into(), 16))
                         }
-                        match frame.channels {
-                            1 => {
-                                for sample in frame.data {
-                                    stereo_samples.push(Frame::from_i32(
-                                        sample.into(),
-                                        sample.into(),
-                                        16,
-                                    ))
-                                }
-                            }
-                            2 => {
-                                let mut iter = frame.data.iter();
-                                while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
-                                    stereo_samples.push(Frame::from_i32(
-                                        (*left).into(),
-                                        (*right).into(),
-                                        16,
-                                    ))
-                                }
-                            }
-                            _ => {
-                                return Err(Error::from(
-                                    SoundFromFileError::UnsupportedChannelConfiguration,
-                                ))
-                            }
+                    }
+                    2 => {
+                        let mut iter = frame.data.iter();
+                        while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
+                            stereo_samples.push(Frame::from_i32(
+                                (*left).into(),
+                                (*right).into(),
+                                16,
+                            ))
                         }
                     }
-                    Err(error) => match error {
-                        minimp3::Error::Eof => break,
-                        error => return Err(error.into()),
-                    },
+                    _ => {
+                        return Err(Error::from(
+                            SoundFromFileError::UnsupportedChannelConfiguration,
+                        ))
+                    }
                 }
             }
-            let sample_rate = match sample_rate {
-                Some(sample_rate) => sample_rate,
-                None => return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat)),
-            };
-
-            load_context.set_default_asset(LoadedAsset::new(AudioSource {
-                sound: Sound::from_frames(
-                    sample_rate as u32,
-                    stereo_samples,
-                    SoundSettings::default(),
-                ),
-            }));
-            Ok(())
-        })
+            Err(error) => match error {
+                minimp3::Error::Eof => break,
+                error => return Err(error.into()),
+            },
+        }
     }
+    let sample_rate = match sample_rate {
+        Some(sample_rate) => sample_rate,
+        None => return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat)),
+    };
 
-    fn extensions(&self) -> &[&str] {
-        &["mp3"]
-    }
+    Ok(create_audio_source(
+        sample_rate as u32,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
 }
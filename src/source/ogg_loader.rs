@@ -7,7 +7,7 @@ use bevy::utils::BoxedFuture;
 #[cfg(feature = "ogg")]
 use kira::sound::error::SoundFromFileError;
 #[cfg(feature = "ogg")]
-use kira::sound::{Sound, SoundSettings};
+use kira::sound::SoundSettings;
 #[cfg(feature = "ogg")]
 use kira::Frame;
 #[cfg(feature = "ogg")]
@@ -15,11 +15,18 @@ use lewton::{inside_ogg::OggStreamReader, samples::Samples};
 #[cfg(feature = "ogg")]
 use std::io::Cursor;
 
+#[cfg(feature = "ogg")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "ogg")]
+use crate::source::AudioLoadSettings;
 #[cfg(feature = "ogg")]
 use crate::source::AudioSource;
 
-#[derive(Default)]
-pub struct OggLoader;
+#[cfg(feature = "ogg")]
+pub struct OggLoader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
 
 #[cfg(feature = "ogg")]
 impl AssetLoader for OggLoader {
@@ -29,42 +36,55 @@ impl AssetLoader for OggLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut reader = OggStreamReader::new(Cursor::new(bytes))?;
-            let mut stereo_samples = vec![];
-            while let Some(packet) = reader.read_dec_packet_generic::<Vec<Vec<f32>>>()? {
-                let num_channels = packet.len();
-                let num_samples = packet.num_samples();
-                match num_channels {
-                    1 => {
-                        for i in 0..num_samples {
-                            stereo_samples.push(Frame::from_mono(packet[0][i]));
-                        }
-                    }
-                    2 => {
-                        for i in 0..num_samples {
-                            stereo_samples.push(Frame::new(packet[0][i], packet[1][i]));
-                        }
-                    }
-                    _ => {
-                        return Err(Error::from(
-                            SoundFromFileError::UnsupportedChannelConfiguration,
-                        ))
-                    }
-                }
-            }
-
-            load_context.set_default_asset(LoadedAsset::new(AudioSource {
-                sound: Sound::from_frames(
-                    reader.ident_hdr.audio_sample_rate,
-                    stereo_samples,
-                    SoundSettings::default(),
-                ),
-            }));
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_ogg(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["ogg"]
+        &self.extensions
     }
 }
+
+#[cfg(feature = "ogg")]
+pub(crate) fn decode_ogg(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let mut reader = OggStreamReader::new(Cursor::new(bytes))?;
+    let mut stereo_samples = vec![];
+    while let Some(packet) = reader.read_dec_packet_generic::<Vec<Vec<f32>>>()? {
+        let num_channels = packet.len();
+        let num_samples = packet.num_samples();
+        match num_channels {
+            1 => {
+                for i in 0..num_samples {
+                    stereo_samples.push(Frame::from_mono(packet[0][i]));
+                }
+            }
+            2 => {
+                for i in 0..num_samples {
+                    stereo_samples.push(Frame::new(packet[0][i], packet[1][i]));
+                }
+            }
+            _ => {
+                return Err(Error::from(
+                    SoundFromFileError::UnsupportedChannelConfiguration,
+                ))
+            }
+        }
+    }
+
+    Ok(create_audio_source(
+        reader.ident_hdr.audio_sample_rate,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
+}
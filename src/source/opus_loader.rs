@@ -0,0 +1,120 @@
+#[cfg(feature = "opus")]
+use anyhow::{Error, Result};
+#[cfg(feature = "opus")]
+use audiopus::coder::Decoder;
+#[cfg(feature = "opus")]
+use audiopus::{Channels, SampleRate};
+#[cfg(feature = "opus")]
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+#[cfg(feature = "opus")]
+use bevy::utils::BoxedFuture;
+#[cfg(feature = "opus")]
+use kira::sound::error::SoundFromFileError;
+#[cfg(feature = "opus")]
+use kira::sound::SoundSettings;
+#[cfg(feature = "opus")]
+use kira::Frame;
+#[cfg(feature = "opus")]
+use ogg_container::reading::PacketReader;
+#[cfg(feature = "opus")]
+use std::io::Cursor;
+
+#[cfg(feature = "opus")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "opus")]
+use crate::source::AudioLoadSettings;
+#[cfg(feature = "opus")]
+use crate::source::AudioSource;
+
+/// Opus streams are always decoded at 48 kHz
+#[cfg(feature = "opus")]
+const OPUS_SAMPLE_RATE: u32 = 48000;
+/// The longest Opus packet is 120 ms
+#[cfg(feature = "opus")]
+const MAX_PACKET_SAMPLES: usize = 5760;
+
+#[cfg(feature = "opus")]
+pub struct OpusLoader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
+
+#[cfg(feature = "opus")]
+impl AssetLoader for OpusLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_opus(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
+
+/// Decode an Ogg Opus file
+#[cfg(feature = "opus")]
+pub(crate) fn decode_opus(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let mut reader = PacketReader::new(Cursor::new(bytes));
+    // The identification header contains the channel count and the number
+    // of samples at the start that only prime the decoder
+    let header = match reader.read_packet()? {
+        Some(packet) if packet.data.len() >= 19 && packet.data.starts_with(b"OpusHead") => {
+            packet.data
+        }
+        _ => return Err(Error::from(SoundFromFileError::UnsupportedAudioFileFormat)),
+    };
+    let channels = match header[9] {
+        1 => Channels::Mono,
+        2 => Channels::Stereo,
+        _ => {
+            return Err(Error::from(
+                SoundFromFileError::UnsupportedChannelConfiguration,
+            ))
+        }
+    };
+    let pre_skip = u16::from_le_bytes([header[10], header[11]]) as usize;
+    // The comment header is not needed
+    reader.read_packet()?;
+
+    let mut decoder = Decoder::new(SampleRate::Hz48000, channels)?;
+    let mut output = vec![0.0; MAX_PACKET_SAMPLES * 2];
+    let mut stereo_samples = vec![];
+    while let Some(packet) = reader.read_packet()? {
+        let samples = decoder.decode_float(Some(&packet.data), &mut output, false)?;
+        match channels {
+            Channels::Stereo => {
+                for frame in output[..samples * 2].chunks_exact(2) {
+                    stereo_samples.push(Frame::new(frame[0], frame[1]));
+                }
+            }
+            _ => {
+                for sample in output[..samples].iter() {
+                    stereo_samples.push(Frame::new(*sample, *sample));
+                }
+            }
+        }
+    }
+    stereo_samples.drain(..pre_skip.min(stereo_samples.len()));
+
+    Ok(create_audio_source(
+        OPUS_SAMPLE_RATE,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
+}
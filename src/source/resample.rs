@@ -0,0 +1,154 @@
+use crate::source::loop_seam::LoopSeam;
+use crate::source::AudioSource;
+use kira::sound::{Sound, SoundSettings};
+use kira::Frame;
+use std::f64::consts::PI;
+
+/// Zero crossings of the sinc kernel on each side of a sample
+const SINC_ZERO_CROSSINGS: usize = 16;
+
+/// How audio sources are resampled while loading
+///
+/// Better quality takes more time to load, but costs nothing during playback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResamplingQuality {
+    /// Straight lines between samples
+    ///
+    /// The fastest option, but it dulls high frequencies and can add audible aliasing.
+    Linear,
+    /// Cubic curves through the surrounding samples
+    ///
+    /// This is the interpolation Kira uses during playback. It is cheap and good enough
+    /// for most sound effects.
+    Cubic,
+    /// A windowed sinc filter
+    ///
+    /// The cleanest option and well suited for music, but loading takes a lot longer,
+    /// especially in debug builds.
+    Sinc,
+}
+
+/// Resample audio sources to a fixed sample rate while loading
+///
+/// Kira plays sounds at any sample rate by interpolating between their samples for each
+/// frame it outputs. For sounds at the sample rate of the output device (usually 48 kHz),
+/// the interpolated positions fall on the samples themselves. Resampling them once with a
+/// high quality filter, for example from 44.1 kHz, avoids the artifacts of interpolating
+/// during playback. Sounds take more memory when their sample rate is raised.
+///
+/// Set it with [`AudioLoadSettings::set_resampling`](crate::AudioLoadSettings::set_resampling).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResampleSettings {
+    /// The sample rate in Hz to resample to
+    pub sample_rate: u32,
+    /// How the samples in between are calculated
+    pub quality: ResamplingQuality,
+}
+
+impl Default for ResampleSettings {
+    fn default() -> Self {
+        ResampleSettings {
+            sample_rate: 48000,
+            quality: ResamplingQuality::Sinc,
+        }
+    }
+}
+
+/// Create an audio source from decoded frames, resampling them if configured
+#[cfg_attr(
+    not(any(
+        feature = "mp3",
+        feature = "ogg",
+        feature = "wav",
+        feature = "flac",
+        feature = "aac",
+        feature = "opus"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn create_audio_source(
+    sample_rate: u32,
+    frames: Vec<Frame>,
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> AudioSource {
+    let (sample_rate, frames) = match resampling {
+        Some(resampling) if resampling.sample_rate != sample_rate && sample_rate > 0 => (
+            resampling.sample_rate,
+            resample(&frames, sample_rate, resampling),
+        ),
+        _ => (sample_rate, frames),
+    };
+    AudioSource {
+        loop_seam: LoopSeam::new(sample_rate, &frames),
+        sound: Sound::from_frames(sample_rate, frames, settings),
+    }
+}
+
+fn resample(frames: &[Frame], sample_rate: u32, resampling: ResampleSettings) -> Vec<Frame> {
+    // Input samples per output sample
+    let step = sample_rate as f64 / resampling.sample_rate as f64;
+    let length = (frames.len() as f64 / step).ceil() as usize;
+    let frame = |index: isize| {
+        if index < 0 {
+            return Frame::from_mono(0.0);
+        }
+        frames
+            .get(index as usize)
+            .copied()
+            .unwrap_or_else(|| Frame::from_mono(0.0))
+    };
+    (0..length)
+        .map(|index| {
+            let position = index as f64 * step;
+            let current = position.floor() as isize;
+            let x = (position - position.floor()) as f32;
+            match resampling.quality {
+                ResamplingQuality::Linear => {
+                    let (y0, y1) = (frame(current), frame(current + 1));
+                    y0 + (y1 - y0) * x
+                }
+                ResamplingQuality::Cubic => {
+                    let (y0, y1, y2, y3) = (
+                        frame(current - 1),
+                        frame(current),
+                        frame(current + 1),
+                        frame(current + 2),
+                    );
+                    let c0 = y1;
+                    let c1 = (y2 - y0) * 0.5;
+                    let c2 = y0 - y1 * 2.5 + y2 * 2.0 - y3 * 0.5;
+                    let c3 = (y3 - y0) * 0.5 + (y1 - y2) * 1.5;
+                    ((c3 * x + c2) * x + c1) * x + c0
+                }
+                ResamplingQuality::Sinc => sinc_interpolate(&frame, position, step),
+            }
+        })
+        .collect()
+}
+
+/// Interpolate with a Blackman windowed sinc kernel
+///
+/// When lowering the sample rate, the kernel is widened to filter out the
+/// frequencies the new sample rate can not represent.
+fn sinc_interpolate(frame: &impl Fn(isize) -> Frame, position: f64, step: f64) -> Frame {
+    let cutoff = (1.0 / step).min(1.0);
+    let half_width = SINC_ZERO_CROSSINGS as f64 / cutoff;
+    let first = (position - half_width).ceil() as isize;
+    let last = (position + half_width).floor() as isize;
+    let mut sum = Frame::from_mono(0.0);
+    for index in first..=last {
+        let distance = position - index as f64;
+        let x = distance * cutoff;
+        let sinc = if x == 0.0 {
+            1.0
+        } else {
+            (PI * x).sin() / (PI * x)
+        };
+        let window_position = 0.5 + distance / (2.0 * half_width);
+        let window = 0.42 - 0.5 * (2.0 * PI * window_position).cos()
+            + 0.08 * (4.0 * PI * window_position).cos();
+        sum += frame(index) * (sinc * window * cutoff) as f32;
+    }
+    sum
+}
@@ -9,15 +9,22 @@ use hound::WavReader;
 #[cfg(feature = "wav")]
 use kira::sound::error::SoundFromFileError;
 #[cfg(feature = "wav")]
-use kira::sound::{Sound, SoundSettings};
+use kira::sound::SoundSettings;
 #[cfg(feature = "wav")]
 use kira::Frame;
 
+#[cfg(feature = "wav")]
+use crate::source::resample::{create_audio_source, ResampleSettings};
+#[cfg(feature = "wav")]
+use crate::source::AudioLoadSettings;
 #[cfg(feature = "wav")]
 use crate::source::AudioSource;
 
-#[derive(Default)]
-pub struct WavLoader;
+#[cfg(feature = "wav")]
+pub struct WavLoader {
+    pub(crate) extensions: Vec<&'static str>,
+    pub(crate) load_settings: AudioLoadSettings,
+}
 
 #[cfg(feature = "wav")]
 impl AssetLoader for WavLoader {
@@ -27,64 +34,77 @@ impl AssetLoader for WavLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut reader = WavReader::new(bytes)?;
-            let spec = reader.spec();
-            let mut stereo_samples = vec![];
-            match reader.spec().channels {
-                1 => match spec.sample_format {
-                    hound::SampleFormat::Float => {
-                        for sample in reader.samples::<f32>() {
-                            stereo_samples.push(Frame::from_mono(sample?))
-                        }
-                    }
-                    hound::SampleFormat::Int => {
-                        for sample in reader.samples::<i32>() {
-                            let sample = sample?;
-                            stereo_samples.push(Frame::from_i32(
-                                sample,
-                                sample,
-                                spec.bits_per_sample.into(),
-                            ));
-                        }
-                    }
-                },
-                2 => match spec.sample_format {
-                    hound::SampleFormat::Float => {
-                        let mut iter = reader.samples::<f32>();
-                        while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
-                            stereo_samples.push(Frame::new(left?, right?));
-                        }
-                    }
-                    hound::SampleFormat::Int => {
-                        let mut iter = reader.samples::<i32>();
-                        while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
-                            stereo_samples.push(Frame::from_i32(
-                                left?,
-                                right?,
-                                spec.bits_per_sample.into(),
-                            ));
-                        }
-                    }
-                },
-                _ => {
-                    return Err(Error::from(
-                        SoundFromFileError::UnsupportedChannelConfiguration,
-                    ))
-                }
-            }
-
-            load_context.set_default_asset(LoadedAsset::new(AudioSource {
-                sound: Sound::from_frames(
-                    reader.spec().sample_rate,
-                    stereo_samples,
-                    SoundSettings::default(),
-                ),
-            }));
+            let settings = self.load_settings.get(load_context.path());
+            load_context.set_default_asset(LoadedAsset::new(decode_wav(
+                bytes,
+                settings,
+                self.load_settings.resampling(),
+            )?));
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["wav"]
+        &self.extensions
+    }
+}
+
+#[cfg(feature = "wav")]
+pub(crate) fn decode_wav(
+    bytes: &[u8],
+    settings: SoundSettings,
+    resampling: Option<ResampleSettings>,
+) -> Result<AudioSource> {
+    let mut reader = WavReader::new(bytes)?;
+    let spec = reader.spec();
+    let mut stereo_samples = vec![];
+    match reader.spec().channels {
+        1 => match spec.sample_format {
+            hound::SampleFormat::Float => {
+                for sample in reader.samples::<f32>() {
+                    stereo_samples.push(Frame::from_mono(sample?))
+                }
+            }
+            hound::SampleFormat::Int => {
+                for sample in reader.samples::<i32>() {
+                    let sample = sample?;
+                    stereo_samples.push(Frame::from_i32(
+                        sample,
+                        sample,
+                        spec.bits_per_sample.into(),
+                    ));
+                }
+            }
+        },
+        2 => match spec.sample_format {
+            hound::SampleFormat::Float => {
+                let mut iter = reader.samples::<f32>();
+                while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
+                    stereo_samples.push(Frame::new(left?, right?));
+                }
+            }
+            hound::SampleFormat::Int => {
+                let mut iter = reader.samples::<i32>();
+                while let (Some(left), Some(right)) = (iter.next(), iter.next()) {
+                    stereo_samples.push(Frame::from_i32(
+                        left?,
+                        right?,
+                        spec.bits_per_sample.into(),
+                    ));
+                }
+            }
+        },
+        _ => {
+            return Err(Error::from(
+                SoundFromFileError::UnsupportedChannelConfiguration,
+            ))
+        }
     }
+
+    Ok(create_audio_source(
+        reader.spec().sample_rate,
+        stereo_samples,
+        settings,
+        resampling,
+    ))
 }
@@ -0,0 +1,222 @@
+use crate::audio::Audio;
+use crate::instance::InstanceHandle;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::cmp::Ordering;
+
+/// Marks the entity that spatial audio is heard from
+///
+/// Usually this is the camera or the player. For split-screen games, every player's
+/// camera can be a listener. How an emitter is heard by several listeners is set by the
+/// [`ListenerMixing`] resource.
+///
+/// Panning follows the orientation of the listener: emitters on its local right side
+/// are heard on the right.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::AudioListener;
+///
+/// fn setup(commands: &mut Commands) {
+///     commands
+///         .spawn((Transform::default(), GlobalTransform::default()))
+///         .with(AudioListener::default());
+/// }
+/// ```
+#[derive(Default, Clone, Copy, Debug)]
+pub struct AudioListener {
+    /// Ignore the depth axis of the listener when computing distances
+    ///
+    /// Enable this in 2D games, where the camera is placed far away from the sprites.
+    pub ignore_depth: bool,
+}
+
+/// How an emitter is heard when there are several [`AudioListener`]s
+///
+/// The plugin adds this resource from [`AudioSettings::listener_mixing`](crate::AudioSettings::listener_mixing).
+/// It can be changed at runtime. With a single listener, all modes sound the same.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::ListenerMixing;
+///
+/// fn enter_split_screen(mut mixing: ResMut<ListenerMixing>) {
+///     *mixing = ListenerMixing::Blend;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListenerMixing {
+    /// The listener that hears an emitter the loudest sets its volume and panning
+    ///
+    /// Each emitter sounds like it does for the player closest to it.
+    Loudest,
+    /// The emitter has the volume of the loudest listener and a panning in between
+    ///
+    /// The pannings of all listeners are averaged, weighted by how loud they hear the
+    /// emitter. An emitter that is to the left of one player and to the right of
+    /// another at the same distance is heard in the center.
+    Blend,
+}
+
+/// How the volume of an emitter falls off with its distance to the listener
+///
+/// Distances are clamped to the minimum and maximum distance of the emitter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rolloff {
+    /// The volume decreases linearly from the minimum to the maximum distance
+    ///
+    /// With a rolloff factor of 1, the emitter is silent at its maximum distance.
+    Linear,
+    /// The volume is inversely proportional to the distance
+    ///
+    /// This is how sound behaves physically.
+    Inverse,
+    /// The volume decreases exponentially with the distance
+    Exponential,
+}
+
+/// Makes the instances of an entity sound like they are coming from its position
+///
+/// Every frame, the panning and volume of the instances are updated based on the
+/// direction and the distance from the [`AudioListener`] to the emitter. With several
+/// listeners, they are combined according to [`ListenerMixing`]. Changes are smoothed
+/// over a few frames, so fast moving emitters glide instead of jumping. The attenuation is
+/// multiplied with the volume of each instance, so instances keep the volume they were
+/// played with or set to by [`Audio::set_instance_volume`](crate::Audio::set_instance_volume).
+/// Despawning the entity or removing this component stops its instances.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioEmitter};
+///
+/// fn spawn_waterfall(commands: &mut Commands, asset_server: Res<AssetServer>, audio: Res<Audio>) {
+///     let instance = audio.play_looped(asset_server.load("waterfall.ogg"));
+///     commands
+///         .spawn((Transform::from_translation(Vec3::new(200., 0., 0.)), GlobalTransform::default()))
+///         .with(AudioEmitter {
+///             instances: vec![instance],
+///             ..Default::default()
+///         });
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AudioEmitter {
+    /// The instances that are played from this emitter
+    pub instances: Vec<InstanceHandle>,
+    /// Up to this distance, the emitter is heard at full volume
+    pub min_distance: f32,
+    /// From this distance on, the volume of the emitter no longer decreases
+    pub max_distance: f32,
+    /// The shape of the volume falloff between the minimum and maximum distance
+    pub rolloff: Rolloff,
+    /// How fast the volume falls off
+    ///
+    /// The default value is 1. Higher values make the volume drop faster.
+    pub rolloff_factor: f32,
+}
+
+impl Default for AudioEmitter {
+    fn default() -> Self {
+        AudioEmitter {
+            instances: vec![],
+            min_distance: 1.0,
+            max_distance: 1000.0,
+            rolloff: Rolloff::Linear,
+            rolloff_factor: 1.0,
+        }
+    }
+}
+
+impl AudioEmitter {
+    /// The volume of the emitter's instances at the given distance to the listener
+    fn attenuation(&self, distance: f32) -> f32 {
+        let min_distance = self.min_distance.max(0.0);
+        let max_distance = self.max_distance.max(min_distance);
+        let distance = distance.clamp(min_distance, max_distance);
+        let factor = self.rolloff_factor.max(0.0);
+        let volume = match self.rolloff {
+            Rolloff::Linear if max_distance > min_distance => {
+                1.0 - factor * (distance - min_distance) / (max_distance - min_distance)
+            }
+            Rolloff::Linear => 1.0,
+            Rolloff::Inverse if min_distance > 0.0 => {
+                min_distance / (min_distance + factor * (distance - min_distance))
+            }
+            Rolloff::Exponential if min_distance > 0.0 => (distance / min_distance).powf(-factor),
+            // Without a minimum distance, the volume would be infinite at the listener
+            Rolloff::Inverse | Rolloff::Exponential => 1.0,
+        };
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+/// The panning for an emitter at the given offset in the local space of the listener
+///
+/// Emitters straight to the right of the listener are panned fully to the right.
+fn panning(local_offset: Vec3) -> f32 {
+    let distance = local_offset.length();
+    if distance <= f32::EPSILON {
+        return 0.5;
+    }
+    0.5 + 0.5 * (local_offset.x / distance).clamp(-1.0, 1.0)
+}
+
+/// The volume and panning of an emitter at the given position for one listener
+fn hear(
+    emitter: &AudioEmitter,
+    translation: Vec3,
+    (listener_transform, listener): (&GlobalTransform, &AudioListener),
+) -> (f32, f32) {
+    let mut local_offset =
+        listener_transform.rotation.conjugate() * (translation - listener_transform.translation);
+    if listener.ignore_depth {
+        local_offset.z = 0.0;
+    }
+    (
+        emitter.attenuation(local_offset.length()),
+        panning(local_offset),
+    )
+}
+
+pub(crate) fn update_spatial_audio_system(
+    audio: Res<Audio>,
+    mixing: Res<ListenerMixing>,
+    mut emitter_instances: Local<HashMap<Entity, Vec<InstanceHandle>>>,
+    listeners: Query<(&GlobalTransform, &AudioListener)>,
+    emitters: Query<(Entity, &GlobalTransform, &AudioEmitter)>,
+) {
+    for entity in emitters.removed::<AudioEmitter>() {
+        if let Some(instances) = emitter_instances.remove(entity) {
+            for instance in instances.iter() {
+                audio.stop_instance(instance);
+            }
+        }
+    }
+    for (entity, transform, emitter) in emitters.iter() {
+        emitter_instances.insert(entity, emitter.instances.clone());
+        let heard: Vec<(f32, f32)> = listeners
+            .iter()
+            .map(|listener| hear(emitter, transform.translation, listener))
+            .collect();
+        let loudest = heard
+            .iter()
+            .copied()
+            .max_by(|(first, _), (second, _)| first.partial_cmp(second).unwrap_or(Ordering::Equal));
+        let (volume, panning) = match (loudest, *mixing) {
+            (None, _) => continue,
+            (Some(loudest), ListenerMixing::Loudest) => loudest,
+            (Some((volume, panning)), ListenerMixing::Blend) => {
+                let total: f32 = heard.iter().map(|(volume, _)| volume).sum();
+                if total > 0.0 {
+                    let blended = heard
+                        .iter()
+                        .map(|(volume, panning)| volume * panning)
+                        .sum::<f32>();
+                    (volume, blended / total)
+                } else {
+                    (volume, panning)
+                }
+            }
+        };
+        for instance in emitter.instances.iter() {
+            audio.set_instance_spatial(volume, panning, instance);
+        }
+    }
+}
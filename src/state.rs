@@ -0,0 +1,341 @@
+use crate::channel::AudioChannel;
+use crate::instance::InstanceHandle;
+use crate::meter::AudioLevel;
+use crate::mixer::AudioMixerTrack;
+use kira::instance::handle::InstanceHandle as KiraInstanceHandle;
+use kira::instance::InstanceState;
+use std::collections::HashMap;
+
+/// Bevy resource with information about the audio playing in each channel
+///
+/// The plugin updates this resource once per frame after running the queued
+/// audio commands. Systems can read it at any time to inspect the playback.
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{AudioChannel, AudioState};
+///
+/// fn my_system(audio_state: Res<AudioState>) {
+///     if let Some(position) = audio_state.playback_position(&AudioChannel::default()) {
+///         println!("The default channel is {} seconds into its audio", position);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct AudioState {
+    pub(crate) channels: HashMap<AudioChannel, ChannelPlaybackState>,
+    /// The state of every instance that was not removed yet, by instance id
+    pub(crate) instances: HashMap<u64, PlaybackState>,
+    pub(crate) master_level: AudioLevel,
+    pub(crate) track_levels: HashMap<AudioMixerTrack, AudioLevel>,
+    /// The Kira handles of every instance that was not removed yet, by instance id
+    pub(crate) kira_instances: HashMap<u64, KiraInstanceHandle>,
+}
+
+/// Whether an instance or a channel is playing
+///
+/// Get it with [`Audio::instance_state`](crate::Audio::instance_state) or
+/// [`Audio::channel_state`](crate::Audio::channel_state).
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, InstanceHandle, PlaybackState};
+///
+/// fn advance_dialog(audio: Res<Audio>, line: Res<InstanceHandle>) {
+///     if audio.instance_state(&line) == PlaybackState::Stopped {
+///         info!("The line is over, show the next one");
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlaybackState {
+    /// Waiting to start playing, for example until its audio source is loaded
+    Queued,
+    /// Playing or fading in
+    Playing,
+    /// Fading out before pausing
+    Pausing,
+    /// Paused and keeping its position
+    Paused,
+    /// Fading out before stopping
+    Stopping,
+    /// Finished, stopped or never played
+    Stopped,
+}
+
+impl PlaybackState {
+    pub(crate) fn from_instance_state(state: InstanceState) -> Self {
+        match state {
+            InstanceState::Playing => PlaybackState::Playing,
+            InstanceState::Pausing(_) => PlaybackState::Pausing,
+            InstanceState::Paused(_) => PlaybackState::Paused,
+            InstanceState::Stopping => PlaybackState::Stopping,
+            InstanceState::Stopped => PlaybackState::Stopped,
+        }
+    }
+
+    /// The state of a channel with instances in the given states
+    ///
+    /// A channel is playing as long as any of its instances is playing.
+    pub(crate) fn of_channel(states: impl Iterator<Item = PlaybackState>) -> Self {
+        let priority = |state: &PlaybackState| match state {
+            PlaybackState::Playing => 5,
+            PlaybackState::Queued => 4,
+            PlaybackState::Pausing => 3,
+            PlaybackState::Stopping => 2,
+            PlaybackState::Paused => 1,
+            PlaybackState::Stopped => 0,
+        };
+        states
+            .max_by_key(priority)
+            .unwrap_or(PlaybackState::Stopped)
+    }
+}
+
+pub(crate) struct ChannelPlaybackState {
+    pub(crate) playing_instances: usize,
+    pub(crate) active_instances: usize,
+    pub(crate) position: Option<f64>,
+    pub(crate) state: PlaybackState,
+    pub(crate) volume: f32,
+    pub(crate) panning: f32,
+    pub(crate) pitch: f32,
+}
+
+impl Default for ChannelPlaybackState {
+    fn default() -> Self {
+        ChannelPlaybackState {
+            playing_instances: 0,
+            active_instances: 0,
+            position: None,
+            state: PlaybackState::Stopped,
+            volume: 1.0,
+            panning: 0.5,
+            pitch: 1.0,
+        }
+    }
+}
+
+impl AudioState {
+    /// Check if any audio is currently playing in the given channel
+    ///
+    /// Paused audio and audio that finished playing does not count.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     if !audio_state.is_playing(&AudioChannel::new("music".to_owned())) {
+    ///         println!("The music stopped");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_playing(&self, channel: &AudioChannel) -> bool {
+        self.channels
+            .get(channel)
+            .map_or(0, |channel_state| channel_state.playing_instances)
+            > 0
+    }
+
+    /// Get the playback position in seconds of the most recent instance in the given channel
+    ///
+    /// Returns `None` if nothing is playing in the channel. Paused instances report
+    /// the position they were paused at.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let position = audio_state.playback_position(&AudioChannel::new("my-channel".to_owned()));
+    /// }
+    /// ```
+    pub fn playback_position(&self, channel: &AudioChannel) -> Option<f64> {
+        self.channels
+            .get(channel)
+            .and_then(|channel_state| channel_state.position)
+    }
+
+    /// Get the playback state of the given instance
+    ///
+    /// Instances that were not played yet are [`PlaybackState::Stopped`] here, unlike for
+    /// [`Audio::instance_state`](crate::Audio::instance_state), which also knows the
+    /// queued commands.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioState, InstanceHandle, PlaybackState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>, instance: Res<InstanceHandle>) {
+    ///     if audio_state.instance_state(&instance) == PlaybackState::Paused {
+    ///         info!("The instance is paused");
+    ///     }
+    /// }
+    /// ```
+    pub fn instance_state(&self, instance: &InstanceHandle) -> PlaybackState {
+        self.instances
+            .get(&instance.id)
+            .copied()
+            .unwrap_or(PlaybackState::Stopped)
+    }
+
+    /// Get the playback state of the given channel
+    ///
+    /// The channel is playing if any of its instances is playing. Otherwise, it has the
+    /// state of its instances that are fading, then of the paused ones.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState, PlaybackState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let state = audio_state.channel_state(&AudioChannel::new("music".to_owned()));
+    ///     if state == PlaybackState::Paused {
+    ///         info!("The music is paused");
+    ///     }
+    /// }
+    /// ```
+    pub fn channel_state(&self, channel: &AudioChannel) -> PlaybackState {
+        self.channels
+            .get(channel)
+            .map_or(PlaybackState::Stopped, |channel_state| channel_state.state)
+    }
+
+    /// Get all channels that have instances or changed settings, like their volume
+    ///
+    /// The channels are in no particular order.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::AudioState;
+    ///
+    /// fn mixer_ui(audio_state: Res<AudioState>) {
+    ///     for channel in audio_state.active_channels() {
+    ///         println!("{:?} at volume {}", channel, audio_state.volume(&channel));
+    ///     }
+    /// }
+    /// ```
+    pub fn active_channels(&self) -> Vec<AudioChannel> {
+        self.channels.keys().cloned().collect()
+    }
+
+    /// Get the number of instances in the given channel that are playing or paused
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let voices = audio_state.instance_count(&AudioChannel::new("sfx".to_owned()));
+    /// }
+    /// ```
+    pub fn instance_count(&self, channel: &AudioChannel) -> usize {
+        self.channels
+            .get(channel)
+            .map_or(0, |channel_state| channel_state.active_instances)
+    }
+
+    /// Get the volume that is currently applied to the given channel
+    ///
+    /// Unlike [`Audio::channel_volume`](crate::Audio::channel_volume), this includes
+    /// muting, soloing and ducking. The volume of the channel's mixer track applies to the
+    /// mixed audio of the track afterwards, so it is not included.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let volume = audio_state.volume(&AudioChannel::new("music".to_owned()));
+    /// }
+    /// ```
+    pub fn volume(&self, channel: &AudioChannel) -> f32 {
+        self.channels
+            .get(channel)
+            .map_or(1.0, |channel_state| channel_state.volume)
+    }
+
+    /// Get the panning that is currently applied to the given channel
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let panning = audio_state.panning(&AudioChannel::default());
+    /// }
+    /// ```
+    pub fn panning(&self, channel: &AudioChannel) -> f32 {
+        self.channels
+            .get(channel)
+            .map_or(0.5, |channel_state| channel_state.panning)
+    }
+
+    /// Get the pitch (playback rate) that is currently applied to the given channel
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioChannel, AudioState};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>) {
+    ///     let pitch = audio_state.pitch(&AudioChannel::default());
+    /// }
+    /// ```
+    pub fn pitch(&self, channel: &AudioChannel) -> f32 {
+        self.channels
+            .get(channel)
+            .map_or(1.0, |channel_state| channel_state.pitch)
+    }
+
+    /// Get the recent level of the whole output
+    ///
+    /// The level is measured on the audio thread after the master limiter and read
+    /// once per frame. See [`AudioLevel`] for the smoothing.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::AudioState;
+    ///
+    /// fn pulse_with_music(audio_state: Res<AudioState>, mut query: Query<&mut Transform>) {
+    ///     let scale = 1.0 + audio_state.master_level().rms;
+    ///     for mut transform in query.iter_mut() {
+    ///         transform.scale = Vec3::splat(scale);
+    ///     }
+    /// }
+    /// ```
+    pub fn master_level(&self) -> AudioLevel {
+        self.master_level
+    }
+
+    /// Get the recent level of the given mixer track
+    ///
+    /// The level includes the effects of the track. Tracks that were never used have no level.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioMixerTrack, AudioState};
+    ///
+    /// fn music_meter(audio_state: Res<AudioState>) {
+    ///     if let Some(level) = audio_state.track_level(&AudioMixerTrack::new("music".to_owned())) {
+    ///         info!("Music peak: {}", level.peak);
+    ///     }
+    /// }
+    /// ```
+    pub fn track_level(&self, track: &AudioMixerTrack) -> Option<AudioLevel> {
+        self.track_levels.get(track).copied()
+    }
+
+    /// Get the Kira handle of the given instance, to control it with Kira directly
+    ///
+    /// **Unstable and meant for advanced use only.** This escape hatch exposes the handle of
+    /// the Kira version this crate depends on and may change with any release. The plugin
+    /// keeps its own bookkeeping of every instance, like the channel settings, the playback
+    /// position and the finished events. Changing an instance through Kira bypasses it, so
+    /// for example [`AudioState::playback_position`] and the channel volume can be out of
+    /// sync afterwards. Prefer the methods of [`Audio`](crate::Audio) where they exist.
+    ///
+    /// Returns `None` for instances that were not played yet or were already removed.
+    /// ```edition2018
+    /// # use bevy::prelude::*;
+    /// # use bevy_kira_audio::{AudioState, InstanceHandle};
+    ///
+    /// fn my_system(audio_state: Res<AudioState>, instance: Res<InstanceHandle>) {
+    ///     if let Some(mut kira_instance) = audio_state.kira_instance(&instance).cloned() {
+    ///         if let Err(error) = kira_instance.set_pitch(1.5) {
+    ///             warn!("Failed to change the pitch: {:?}", error);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn kira_instance(&self, instance: &InstanceHandle) -> Option<&KiraInstanceHandle> {
+        self.kira_instances.get(&instance.id)
+    }
+}
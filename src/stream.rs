@@ -0,0 +1,172 @@
+use anyhow::{anyhow, Result};
+use kira::audio_stream::AudioStream;
+use kira::Frame;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::sync::Arc;
+
+#[cfg(feature = "ogg")]
+use bevy::log::warn;
+#[cfg(feature = "ogg")]
+use kira::sound::error::SoundFromFileError;
+#[cfg(feature = "ogg")]
+use lewton::{inside_ogg::OggStreamReader, samples::Samples};
+#[cfg(feature = "ogg")]
+use std::fs::File;
+#[cfg(feature = "ogg")]
+use std::io::BufReader;
+#[cfg(feature = "ogg")]
+use std::sync::mpsc::sync_channel;
+#[cfg(feature = "ogg")]
+use std::thread;
+
+/// The number of decoded packets that are buffered ahead of playback
+#[cfg(feature = "ogg")]
+const BUFFERED_PACKETS: usize = 32;
+
+/// Shared state between a playing stream and the audio output
+pub(crate) struct StreamControl {
+    volume: Arc<AtomicU32>,
+    finished: Arc<AtomicBool>,
+}
+
+impl StreamControl {
+    pub(crate) fn set_volume(&self, volume: f64) {
+        self.volume
+            .store((volume as f32).to_bits(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+}
+
+/// Plays audio that is decoded on a separate thread while playing
+///
+/// Only a small number of decoded packets are kept in memory at any time.
+/// If decoding falls behind, the stream outputs silence until it caught up.
+#[derive(Debug)]
+pub(crate) struct FileStream {
+    packets: Receiver<Vec<Frame>>,
+    packet: Vec<Frame>,
+    index: usize,
+    sample_rate: f64,
+    time: f64,
+    previous: Frame,
+    current: Frame,
+    volume: Arc<AtomicU32>,
+    finished: Arc<AtomicBool>,
+}
+
+impl FileStream {
+    /// Start decoding the file at the given path
+    ///
+    /// The stream has to be added to the audio manager to be heard.
+    /// The format is chosen by the file extension.
+    #[cfg_attr(not(feature = "ogg"), allow(unused_variables))]
+    pub(crate) fn open(path: &Path, looped: bool, volume: f64) -> Result<Self> {
+        let decoded: Result<(Receiver<Vec<Frame>>, u32)> =
+            match path.extension().and_then(|extension| extension.to_str()) {
+                #[cfg(feature = "ogg")]
+                Some("ogg") => decode_ogg(path, looped),
+                _ => Err(anyhow!("Streaming is not supported for {:?}", path)),
+            };
+        let (packets, sample_rate) = decoded?;
+        Ok(FileStream {
+            packets,
+            packet: vec![],
+            index: 0,
+            sample_rate: sample_rate as f64,
+            time: 0.0,
+            previous: Frame::from_mono(0.0),
+            current: Frame::from_mono(0.0),
+            volume: Arc::new(AtomicU32::new((volume as f32).to_bits())),
+            finished: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    pub(crate) fn control(&self) -> StreamControl {
+        StreamControl {
+            volume: self.volume.clone(),
+            finished: self.finished.clone(),
+        }
+    }
+
+    fn next_frame(&mut self) -> Frame {
+        while self.index >= self.packet.len() {
+            match self.packets.try_recv() {
+                Ok(packet) => {
+                    self.packet = packet;
+                    self.index = 0;
+                }
+                Err(TryRecvError::Empty) => return Frame::from_mono(0.0),
+                Err(TryRecvError::Disconnected) => {
+                    self.finished.store(true, Ordering::Relaxed);
+                    return Frame::from_mono(0.0);
+                }
+            }
+        }
+        self.index += 1;
+        self.packet[self.index - 1]
+    }
+}
+
+impl AudioStream for FileStream {
+    fn next(&mut self, dt: f64) -> Frame {
+        // Resample to the output sample rate by interpolating between decoded frames
+        self.time += dt * self.sample_rate;
+        while self.time >= 1.0 {
+            self.time -= 1.0;
+            self.previous = self.current;
+            self.current = self.next_frame();
+        }
+        let volume = f32::from_bits(self.volume.load(Ordering::Relaxed));
+        (self.previous + (self.current - self.previous) * self.time as f32) * volume
+    }
+}
+
+#[cfg(feature = "ogg")]
+fn decode_ogg(path: &Path, looped: bool) -> Result<(Receiver<Vec<Frame>>, u32)> {
+    let mut reader = OggStreamReader::new(BufReader::new(File::open(path)?))?;
+    let sample_rate = reader.ident_hdr.audio_sample_rate;
+    let (sender, receiver) = sync_channel(BUFFERED_PACKETS);
+    thread::spawn(move || {
+        let mut decoded_since_start = false;
+        loop {
+            let packet = match reader.read_dec_packet_generic::<Vec<Vec<f32>>>() {
+                Ok(Some(packet)) => packet,
+                Ok(None) if looped && decoded_since_start => {
+                    decoded_since_start = false;
+                    if reader.seek_absgp_pg(0).is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                _ => return,
+            };
+            let num_samples = packet.num_samples();
+            let frames = match packet.len() {
+                1 => (0..num_samples)
+                    .map(|i| Frame::from_mono(packet[0][i]))
+                    .collect(),
+                2 => (0..num_samples)
+                    .map(|i| Frame::new(packet[0][i], packet[1][i]))
+                    .collect(),
+                _ => {
+                    warn!(
+                        "Stopped streaming: {:?}",
+                        SoundFromFileError::UnsupportedChannelConfiguration
+                    );
+                    return;
+                }
+            };
+            decoded_since_start = true;
+            // The receiving stream was removed
+            if sender.send(frames).is_err() {
+                return;
+            }
+        }
+    });
+    Ok((receiver, sample_rate))
+}
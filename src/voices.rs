@@ -0,0 +1,25 @@
+/// What happens when a channel plays more instances than its maximum number of voices
+///
+/// ```edition2018
+/// # use bevy::prelude::*;
+/// # use bevy_kira_audio::{Audio, AudioChannel, VoiceStealing};
+///
+/// fn my_system(audio: Res<Audio>) {
+///     audio.set_max_voices_in_channel(
+///         8,
+///         VoiceStealing::StealOldest,
+///         &AudioChannel::new("gunshots".to_owned()),
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoiceStealing {
+    /// Stop the instance that started playing first to make room for the new one
+    StealOldest,
+    /// Stop the instance with the lowest instance volume to make room for the new one
+    ///
+    /// Instances with the same volume are stolen from oldest to newest.
+    StealQuietest,
+    /// Do not play the new instance
+    DropNew,
+}
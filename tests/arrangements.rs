@@ -0,0 +1,58 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_kira_audio::{Audio, AudioError, AudioSettings, AudioSource};
+use common::{add_tone, headless_app_with};
+use std::time::Duration;
+
+/// Play the audio in an app that has room for the given number of arrangements
+///
+/// Returns how many arrangements could not be added, because there was no room left.
+fn arrangements_without_room(
+    num_arrangements: usize,
+    play: impl FnOnce(&Audio, Handle<AudioSource>),
+) -> usize {
+    let mut app = headless_app_with(AudioSettings {
+        num_arrangements,
+        ..Default::default()
+    });
+    let tone = add_tone(&mut app, 1.0);
+    play(&app.resources.get::<Audio>().unwrap(), tone);
+    app.update();
+    let events = app.resources.get::<Events<AudioError>>().unwrap();
+    events
+        .get_reader()
+        .iter(&events)
+        .filter(|error| matches!(error, AudioError::AddArrangement { .. }))
+        .count()
+}
+
+#[test]
+fn plays_differing_in_instance_settings_share_their_arrangement() {
+    let missing = arrangements_without_room(1, |audio, tone| {
+        audio.play(tone.clone());
+        audio.play_with_cooldown(tone.clone(), Duration::from_millis(50));
+        audio.play_from(tone, 0.5);
+    });
+
+    assert_eq!(missing, 0);
+}
+
+#[test]
+fn plays_differing_in_arrangement_settings_do_not_collide() {
+    let play = |audio: &Audio, tone: Handle<AudioSource>| {
+        audio.play(tone.clone());
+        audio.play_looped(tone.clone());
+        audio.play_looped_with_region(tone.clone(), 0.25, 0.75);
+        audio.play_looped_with_region(tone.clone(), 0.25, 0.5);
+        audio.play_reversed(tone.clone());
+        audio.play_looped_reversed(tone.clone());
+        audio.play_repeated(tone.clone(), 2);
+        audio.play_repeated(tone, 3);
+    };
+
+    assert_eq!(arrangements_without_room(8, play), 0);
+    assert_eq!(arrangements_without_room(7, play), 1);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy_kira_audio::{Audio, AudioChannel, AudioMixerTrack, AudioState};
+use common::{add_tone, headless_app, run_for};
+use std::time::Duration;
+
+#[test]
+fn same_source_in_two_channels_plays_with_channel_volumes() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 1.0);
+    let quiet = AudioChannel::new("quiet".to_owned());
+    let loud = AudioChannel::new("loud".to_owned());
+    let quiet_track = AudioMixerTrack::new("quiet".to_owned());
+    let loud_track = AudioMixerTrack::new("loud".to_owned());
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        audio.set_channel_track(&quiet, &quiet_track);
+        audio.set_channel_track(&loud, &loud_track);
+        audio.set_volume_in_channel(0.2, &quiet);
+        audio.set_volume_in_channel(0.8, &loud);
+        audio.play_looped_in_channel(tone.clone(), &quiet);
+        audio.play_looped_in_channel(tone, &loud);
+    }
+    run_for(&mut app, Duration::from_secs(1));
+
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert_eq!(audio_state.volume(&quiet), 0.2);
+    assert_eq!(audio_state.volume(&loud), 0.8);
+    assert!(audio_state.is_playing(&quiet));
+    assert!(audio_state.is_playing(&loud));
+    let quiet_peak = audio_state.track_level(&quiet_track).unwrap().peak;
+    let loud_peak = audio_state.track_level(&loud_track).unwrap().peak;
+    assert!(quiet_peak > 0.0);
+    let ratio = loud_peak / quiet_peak;
+    assert!(ratio > 3.5 && ratio < 4.5, "peak ratio {}", ratio);
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_kira_audio::{Audio, AudioChannel, AudioError, AudioSettings, AudioState, PlaybackState};
+use common::{
+    add_tone, add_tone_as, headless_app, headless_app_with, pending_source, run_for, TONE_PEAK,
+};
+use std::time::Duration;
+
+#[test]
+fn global_commands_wait_for_earlier_plays() {
+    let mut app = headless_app();
+    let sfx = AudioChannel::new("sfx".to_owned());
+    let music = AudioChannel::new("music".to_owned());
+    let loading = pending_source(&app);
+    let loaded = add_tone(&mut app, 1.0);
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        audio.play_in_channel(loading.clone(), &sfx);
+        audio.stop_all();
+        audio.play_in_channel(loaded, &music);
+    }
+    app.update();
+    assert!(!app
+        .resources
+        .get::<AudioState>()
+        .unwrap()
+        .is_playing(&music));
+
+    add_tone_as(&mut app, &loading, 1.0);
+    app.update();
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert!(!audio_state.is_playing(&sfx));
+    assert!(audio_state.is_playing(&music));
+}
+
+#[test]
+fn plays_start_at_the_volume_queued_before_them() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 1.0);
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        audio.set_volume(0.8);
+        audio.set_volume(0.3);
+        audio.play_looped(tone);
+    }
+    run_for(&mut app, Duration::from_millis(100));
+
+    let peak = app
+        .resources
+        .get::<AudioState>()
+        .unwrap()
+        .master_level()
+        .peak;
+    assert!(
+        (peak - 0.3 * TONE_PEAK).abs() < 0.01,
+        "master peak {}",
+        peak
+    );
+}
+
+#[test]
+fn commands_after_a_loading_play_keep_their_order() {
+    let mut app = headless_app();
+    let loading = pending_source(&app);
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        audio.set_volume(0.8);
+        audio.play_looped(loading.clone());
+        audio.set_volume(0.3);
+    }
+    app.update();
+
+    // The play starts at 0.8 before the second volume applies, so the peak has to fall off
+    add_tone_as(&mut app, &loading, 1.0);
+    run_for(&mut app, Duration::from_secs(1));
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert!((audio_state.volume(&AudioChannel::default()) - 0.3).abs() < 1e-6);
+    let peak = audio_state.master_level().peak;
+    assert!(
+        (peak - 0.3 * TONE_PEAK).abs() < 0.01,
+        "master peak {}",
+        peak
+    );
+}
+
+#[test]
+fn plays_of_audio_that_does_not_load_are_dropped_after_the_timeout() {
+    let mut app = headless_app_with(AudioSettings {
+        load_timeout: Some(Duration::from_millis(100)),
+        ..Default::default()
+    });
+    let sfx = AudioChannel::new("sfx".to_owned());
+    let loading = pending_source(&app);
+    let loaded = add_tone(&mut app, 1.0);
+    let instance = {
+        let audio = app.resources.get::<Audio>().unwrap();
+        let instance = audio.play_in_channel(loading.clone(), &sfx);
+        audio.play_in_channel(loaded, &sfx);
+        instance
+    };
+    app.update();
+    assert!(!app.resources.get::<AudioState>().unwrap().is_playing(&sfx));
+    assert_eq!(
+        app.resources
+            .get::<Audio>()
+            .unwrap()
+            .instance_state(&instance),
+        PlaybackState::Queued
+    );
+
+    // Events are only kept for two frames, so they are read after every update
+    let mut reader = app
+        .resources
+        .get::<Events<AudioError>>()
+        .unwrap()
+        .get_reader();
+    let mut errors = vec![];
+    for _ in 0..30 {
+        app.update();
+        let events = app.resources.get::<Events<AudioError>>().unwrap();
+        errors.extend(reader.iter(&events).cloned());
+    }
+    assert!(
+        matches!(
+            errors.as_slice(),
+            [AudioError::LoadTimeout { audio_source, channel: Some(channel), .. }]
+                if audio_source == &loading && channel == &sfx
+        ),
+        "errors {:?}",
+        errors
+    );
+    assert_eq!(
+        app.resources
+            .get::<Audio>()
+            .unwrap()
+            .instance_state(&instance),
+        PlaybackState::Stopped
+    );
+    assert_eq!(
+        app.resources
+            .get::<AudioState>()
+            .unwrap()
+            .instance_count(&sfx),
+        1
+    );
+
+    add_tone_as(&mut app, &loading, 1.0);
+    app.update();
+    assert_eq!(
+        app.resources
+            .get::<AudioState>()
+            .unwrap()
+            .instance_count(&sfx),
+        1
+    );
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioPlugin, AudioSettings, AudioSource};
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+use std::time::Duration;
+
+pub const SAMPLE_RATE: u32 = 48_000;
+/// The peak level of a looping [`tone`] at full volume, panned to the center
+pub const TONE_PEAK: f32 = 0.5 * FRAC_1_SQRT_2;
+/// The headless backend processes 10 milliseconds of audio in every update
+pub const FRAMES_PER_UPDATE: u32 = 480;
+
+/// An app with the audio plugin that processes its audio without an output device
+pub fn headless_app() -> App {
+    headless_app_with(AudioSettings::default())
+}
+
+pub fn headless_app_with(settings: AudioSettings) -> App {
+    let mut builder = App::build();
+    builder
+        .add_resource(AudioSettings {
+            headless: true,
+            headless_frames_per_update: Some(FRAMES_PER_UPDATE),
+            ..settings
+        })
+        .add_plugin(bevy::reflect::ReflectPlugin)
+        .add_plugin(bevy::core::CorePlugin)
+        .add_plugin(bevy::asset::AssetPlugin)
+        .add_plugin(AudioPlugin);
+    let mut app = builder.app;
+    app.update();
+    app
+}
+
+/// Add a sine tone of the given length as an audio source
+pub fn add_tone(app: &mut App, seconds: f32) -> Handle<AudioSource> {
+    app.resources
+        .get_mut::<Assets<AudioSource>>()
+        .unwrap()
+        .add(tone(seconds))
+}
+
+pub fn tone(seconds: f32) -> AudioSource {
+    let samples: Vec<f32> = (0..(seconds * SAMPLE_RATE as f32) as usize)
+        .map(|index| (2.0 * PI * 440.0 * index as f32 / SAMPLE_RATE as f32).sin() * 0.5)
+        .collect();
+    AudioSource::from_samples(SAMPLE_RATE, &samples)
+}
+
+/// Run the app for the given audio time
+///
+/// Every update processes [`FRAMES_PER_UPDATE`] frames, no matter how long it takes.
+pub fn run_for(app: &mut App, duration: Duration) {
+    let frames = duration.as_secs_f64() * SAMPLE_RATE as f64;
+    for _ in 0..(frames / FRAMES_PER_UPDATE as f64).ceil() as usize {
+        app.update();
+    }
+}
+
+/// A handle for an audio source that is added later with [`add_tone_as`]
+///
+/// The handle is strong, so the audio source is kept once it is added.
+pub fn pending_source(app: &App) -> Handle<AudioSource> {
+    app.resources
+        .get::<Assets<AudioSource>>()
+        .unwrap()
+        .get_handle(bevy::asset::HandleId::random::<AudioSource>())
+}
+
+/// Add a sine tone of the given length for a handle from [`pending_source`]
+pub fn add_tone_as(app: &mut App, handle: &Handle<AudioSource>, seconds: f32) {
+    app.resources
+        .get_mut::<Assets<AudioSource>>()
+        .unwrap()
+        .set_untracked(handle, tone(seconds));
+}
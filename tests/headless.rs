@@ -0,0 +1,31 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy_kira_audio::{Audio, AudioChannel, AudioState, PlaybackState};
+use common::{add_tone, headless_app, run_for};
+use std::time::Duration;
+
+#[test]
+fn played_audio_is_playing_until_it_ends() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 0.2);
+    let instance = app.resources.get::<Audio>().unwrap().play(tone);
+    app.update();
+    {
+        let audio_state = app.resources.get::<AudioState>().unwrap();
+        assert!(audio_state.is_playing(&AudioChannel::default()));
+        assert_eq!(
+            audio_state.instance_state(&instance),
+            PlaybackState::Playing
+        );
+    }
+
+    run_for(&mut app, Duration::from_millis(400));
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert!(!audio_state.is_playing(&AudioChannel::default()));
+    assert_eq!(
+        audio_state.instance_state(&instance),
+        PlaybackState::Stopped
+    );
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_kira_audio::{Audio, AudioChannel, AudioFinished, AudioState};
+use common::{add_tone, headless_app};
+
+#[test]
+fn finished_instances_are_pruned() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 0.05);
+    let sfx = AudioChannel::new("sfx".to_owned());
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        for _ in 0..20 {
+            audio.play_in_channel(tone.clone(), &sfx);
+        }
+    }
+    app.update();
+    assert_eq!(
+        app.resources
+            .get::<AudioState>()
+            .unwrap()
+            .instance_count(&sfx),
+        20
+    );
+
+    // Instances report that they finished once they are pruned.
+    // Events are only kept for two frames, so they are read after every update.
+    let mut reader = app
+        .resources
+        .get::<Events<AudioFinished>>()
+        .unwrap()
+        .get_reader();
+    let mut finished = 0;
+    for _ in 0..30 {
+        app.update();
+        let events = app.resources.get::<Events<AudioFinished>>().unwrap();
+        finished += reader
+            .iter(&events)
+            .filter(|event| event.channel == sfx)
+            .count();
+    }
+    assert_eq!(finished, 20);
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert_eq!(audio_state.instance_count(&sfx), 0);
+    assert!(!audio_state.is_playing(&sfx));
+}
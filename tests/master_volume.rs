@@ -0,0 +1,30 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy_kira_audio::{Audio, AudioChannel, AudioState};
+use common::{add_tone, headless_app, run_for};
+use std::time::Duration;
+
+#[test]
+fn master_volume_silences_the_master_track_but_keeps_channel_volumes() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 0.5);
+    app.resources.get::<Audio>().unwrap().play_looped(tone);
+    run_for(&mut app, Duration::from_millis(200));
+    assert!(
+        app.resources
+            .get::<AudioState>()
+            .unwrap()
+            .master_level()
+            .rms
+            > 0.1
+    );
+
+    app.resources.get::<Audio>().unwrap().set_master_volume(0.0);
+    run_for(&mut app, Duration::from_millis(1000));
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    assert!(audio_state.master_level().peak < 0.05);
+    assert!(audio_state.is_playing(&AudioChannel::default()));
+    assert_eq!(audio_state.volume(&AudioChannel::default()), 1.0);
+}
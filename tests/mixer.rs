@@ -0,0 +1,43 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy_kira_audio::{Audio, AudioChannel, AudioMixerTrack, AudioState};
+use common::{add_tone, headless_app, run_for, TONE_PEAK};
+use std::time::Duration;
+
+#[test]
+fn track_volume_scales_the_track_but_keeps_channel_volumes() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 1.0);
+    let sfx = AudioMixerTrack::new("sfx".to_owned());
+    let footsteps = AudioChannel::new("footsteps".to_owned());
+    {
+        let audio = app.resources.get::<Audio>().unwrap();
+        audio.set_track_volume(0.5, &sfx);
+        audio.set_channel_track(&footsteps, &sfx);
+        audio.set_volume_in_channel(0.8, &footsteps);
+        audio.play_looped_in_channel(tone, &footsteps);
+    }
+    run_for(&mut app, Duration::from_millis(500));
+    {
+        let audio_state = app.resources.get::<AudioState>().unwrap();
+        let peak = audio_state.track_level(&sfx).unwrap().peak;
+        assert!(
+            (peak - 0.8 * 0.5 * TONE_PEAK).abs() < 0.01,
+            "track peak {}",
+            peak
+        );
+        assert_eq!(audio_state.volume(&footsteps), 0.8);
+    }
+
+    app.resources
+        .get::<Audio>()
+        .unwrap()
+        .set_track_volume(1.0, &sfx);
+    run_for(&mut app, Duration::from_millis(100));
+    let audio_state = app.resources.get::<AudioState>().unwrap();
+    let peak = audio_state.track_level(&sfx).unwrap().peak;
+    assert!((peak - 0.8 * TONE_PEAK).abs() < 0.01, "track peak {}", peak);
+    assert_eq!(audio_state.volume(&footsteps), 0.8);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy_kira_audio::{Audio, AudioChannel, AudioSettings, AudioState, PlaySettings};
+use common::{add_tone_as, headless_app_with, pending_source, run_for, TONE_PEAK};
+use std::time::Duration;
+
+#[test]
+fn reloaded_instances_keep_their_panning() {
+    let mut app = headless_app_with(AudioSettings {
+        restart_on_reload: true,
+        ..Default::default()
+    });
+    let tone = pending_source(&app);
+    add_tone_as(&mut app, &tone, 1.0);
+    app.resources
+        .get::<Audio>()
+        .unwrap()
+        .play_with(tone.clone(), PlaySettings::new().panning(0.0).looped());
+    run_for(&mut app, Duration::from_millis(100));
+
+    add_tone_as(&mut app, &tone, 1.0);
+    run_for(&mut app, Duration::from_secs(1));
+    let state = app.resources.get::<AudioState>().unwrap();
+    assert_eq!(state.instance_count(&AudioChannel::default()), 1);
+    // Hard left, the left channel has the full tone instead of the centered peak
+    let peak = state.master_level().peak;
+    assert!(
+        (peak - TONE_PEAK * std::f32::consts::SQRT_2).abs() < 0.01,
+        "master peak {}",
+        peak
+    );
+}
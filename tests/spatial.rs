@@ -0,0 +1,92 @@
+#![cfg(feature = "headless")]
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_kira_audio::{Audio, AudioEmitter, AudioListener, AudioState, PlaySettings, Rolloff};
+use common::{add_tone, headless_app, run_for, TONE_PEAK};
+use std::time::Duration;
+
+fn master_peak(app: &App) -> f32 {
+    app.resources
+        .get::<AudioState>()
+        .unwrap()
+        .master_level()
+        .peak
+}
+
+#[test]
+fn attenuation_multiplies_with_the_instance_volume() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 1.0);
+    let instance = app
+        .resources
+        .get::<Audio>()
+        .unwrap()
+        .play_with(tone, PlaySettings::new().volume(0.3).looped());
+    app.world
+        .spawn((GlobalTransform::default(), AudioListener::default()));
+    // Straight ahead of the listener, so it is heard in the center at half the volume
+    app.world.spawn((
+        GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 50.0)),
+        AudioEmitter {
+            instances: vec![instance.clone()],
+            min_distance: 0.0,
+            max_distance: 100.0,
+            rolloff: Rolloff::Linear,
+            rolloff_factor: 1.0,
+        },
+    ));
+    run_for(&mut app, Duration::from_secs(1));
+    let peak = master_peak(&app);
+    assert!(
+        (peak - 0.3 * 0.5 * TONE_PEAK).abs() < 0.01,
+        "master peak {}",
+        peak
+    );
+
+    app.resources
+        .get::<Audio>()
+        .unwrap()
+        .set_instance_volume(0.6, &instance);
+    run_for(&mut app, Duration::from_millis(100));
+    let peak = master_peak(&app);
+    assert!(
+        (peak - 0.6 * 0.5 * TONE_PEAK).abs() < 0.01,
+        "master peak {}",
+        peak
+    );
+}
+
+#[test]
+fn moving_emitters_glide_to_their_new_volume() {
+    let mut app = headless_app();
+    let tone = add_tone(&mut app, 1.0);
+    let instance = app.resources.get::<Audio>().unwrap().play_looped(tone);
+    app.world
+        .spawn((GlobalTransform::default(), AudioListener::default()));
+    // At the maximum distance, the emitter is silent
+    let emitter = app.world.spawn((
+        GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 100.0)),
+        AudioEmitter {
+            instances: vec![instance],
+            min_distance: 0.0,
+            max_distance: 100.0,
+            ..Default::default()
+        },
+    ));
+    run_for(&mut app, Duration::from_secs(1));
+    assert!(master_peak(&app) < 0.01);
+
+    app.world
+        .get_mut::<GlobalTransform>(emitter)
+        .unwrap()
+        .translation = Vec3::zero();
+    run_for(&mut app, Duration::from_millis(20));
+    let peak = master_peak(&app);
+    assert!(peak > 0.0 && peak < 0.5 * TONE_PEAK, "master peak {}", peak);
+
+    run_for(&mut app, Duration::from_millis(500));
+    let peak = master_peak(&app);
+    assert!((peak - TONE_PEAK).abs() < 0.01, "master peak {}", peak);
+}